@@ -2,7 +2,25 @@ use crate::auto::traits::{AutoTile, AutoTileRequest, AutoTilemap};
 use crate::coords::TileCoords;
 use bevy::math::IVec2;
 use bevy::utils::{HashMap, HashSet};
-use bevy_tileset_tiles::auto::AutoTileRule;
+use bevy_tileset_tiles::auto::{AutoTileRule, Direction};
+
+/// Controls how the [`AutoTiler`] generates rules from a tile's neighbors
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum RuleGenerationMode {
+	/// Every neighbor is treated independently
+	#[default]
+	Independent,
+	/// Diagonal neighbors only count if both of their adjacent cardinal neighbors are also present
+	///
+	/// This is the behavior expected by standard 47-tile "blob" tilesets, where a lone diagonal
+	/// neighbor should not affect which corner piece is chosen.
+	///
+	/// There is no separate mode for inner corners. A diagonal backed by both of its cardinal
+	/// neighbors is always specified by the generated rule: `Some(true)` if present and `Some(false)`
+	/// if missing. So inner corners (i.e. north and east present, but north-east absent) can be
+	/// authored under this mode by marking the diagonal as `Some(false)`.
+	BlobCorners,
+}
 
 /// A builder object that takes in auto tiles and calculates what changes need to be made
 /// in accordance with their rules.
@@ -14,6 +32,8 @@ pub struct AutoTiler<'a, T: AutoTilemap> {
 	cache: HashMap<<T::Tile as AutoTile>::Coords, T::Tile>,
 	requests: Vec<AutoTileRequest<T::Tile>>,
 	requested: HashSet<<T::Tile as AutoTile>::Coords>,
+	processed: HashSet<<T::Tile as AutoTile>::Coords>,
+	mode: RuleGenerationMode,
 }
 
 impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
@@ -25,10 +45,25 @@ impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
 			tilemap,
 			cache: HashMap::with_capacity_and_hasher(capacity, Default::default()),
 			requested: HashSet::with_capacity_and_hasher(capacity, Default::default()),
+			processed: HashSet::with_capacity_and_hasher(total, Default::default()),
 			requests: Vec::with_capacity(capacity),
+			mode: RuleGenerationMode::default(),
 		}
 	}
 
+	/// Sets how rules are generated from a tile's neighbors
+	///
+	/// # Arguments
+	///
+	/// * `mode`: The generation mode to use
+	///
+	/// returns: AutoTiler<T>
+	///
+	pub fn with_mode(mut self, mode: RuleGenerationMode) -> Self {
+		self.mode = mode;
+		self
+	}
+
 	/// Finish generating the auto tile requests and return them
 	pub fn finish(self) -> Vec<AutoTileRequest<T::Tile>> {
 		self.requests
@@ -47,8 +82,8 @@ impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
 	pub fn add_tile(&mut self, tile: T::Tile, include_self: bool) {
 		let coords = tile.coords();
 
-		if self.requested.contains(&coords) {
-			// Tile has already been updated
+		if !self.processed.insert(coords) {
+			// Tile and its neighbors have already been updated
 			return;
 		}
 
@@ -73,9 +108,45 @@ impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
 		}
 	}
 
+	/// Processes all of the given tiles (and their neighbors) in a single pass
+	///
+	/// This is useful for recomputing every auto tile at once, such as after a bulk load. Each tile
+	/// is only ever requested once, no matter how many of its neighbors are also given.
+	///
+	/// # Arguments
+	///
+	/// * `tiles`: The tiles to add
+	///
+	/// returns: ()
+	pub fn add_tiles<I: IntoIterator<Item = T::Tile>>(&mut self, tiles: I) {
+		for tile in tiles {
+			self.add_tile(tile, true);
+		}
+	}
+
+	/// Computes the rule that would be generated for the given tile based on its current neighbors
+	///
+	/// Unlike [`add_tile`](Self::add_tile), this does not generate any requests, which makes it useful
+	/// for debugging why an auto tile isn't matching the expected rule.
+	///
+	/// # Arguments
+	///
+	/// * `tile`: The tile to compute the rule for (it does not need to exist in the tilemap)
+	///
+	/// returns: AutoTileRule
+	pub fn compute_rule(&mut self, tile: &T::Tile) -> AutoTileRule {
+		let neighbors = self.get_neighbors(tile);
+		let neighbors = self.filter_neighbors(tile, &neighbors);
+		self.generate_rule(&tile.pos(), &neighbors)
+	}
+
 	/// Tries to add a request for the given tile
+	///
+	/// Does nothing if the tile has already been requested.
 	fn try_add_request(&mut self, tile: T::Tile, rule: AutoTileRule) {
-		self.requested.insert(tile.coords());
+		if !self.requested.insert(tile.coords()) {
+			return;
+		}
 		let request = AutoTileRequest { tile, rule };
 		self.requests.push(request);
 	}
@@ -161,9 +232,37 @@ impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
 	// endregion
 
 	/// Generate the rule for a given position based on the surrounding _valid_ neighbors
+	///
+	/// Decorative neighbors are ignored.
 	fn generate_rule(&self, pos: &IVec2, neighbors: &[T::Tile]) -> AutoTileRule {
+		let rule = self.generate_independent_rule(pos, neighbors);
+		match self.mode {
+			RuleGenerationMode::Independent => rule,
+			RuleGenerationMode::BlobCorners => Self::apply_blob_corners(rule),
+		}
+	}
+
+	/// Removes any diagonal connections that are not backed by both adjacent cardinal connections
+	fn apply_blob_corners(mut rule: AutoTileRule) -> AutoTileRule {
+		let corners = [
+			(Direction::NorthEast, Direction::North, Direction::East),
+			(Direction::SouthEast, Direction::South, Direction::East),
+			(Direction::SouthWest, Direction::South, Direction::West),
+			(Direction::NorthWest, Direction::North, Direction::West),
+		];
+		for (corner, a, b) in corners {
+			if rule[a] != Some(true) || rule[b] != Some(true) {
+				rule[corner] = None;
+			}
+		}
+		rule
+	}
+
+	/// Generate the rule for a given position, treating every neighbor independently
+	fn generate_independent_rule(&self, pos: &IVec2, neighbors: &[T::Tile]) -> AutoTileRule {
 		neighbors
 			.iter()
+			.filter(|neighbor| !neighbor.is_decorative())
 			.fold(AutoTileRule::default(), |mut rule, neighbor| {
 				let diff = neighbor.pos() - *pos;
 
@@ -202,3 +301,418 @@ impl<'a, T: AutoTilemap> AutoTiler<'a, T> {
 			})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::auto::AutoTileId;
+	use crate::prelude::{TileGroupId, TileId};
+
+	#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+	struct Coords(IVec2);
+
+	impl TileCoords for Coords {
+		fn pos(&self) -> IVec2 {
+			self.0
+		}
+	}
+
+	#[derive(Debug, Copy, Clone)]
+	struct Tile {
+		pos: IVec2,
+		group_id: TileGroupId,
+		is_decorative: bool,
+	}
+
+	impl AutoTile for Tile {
+		type Coords = Coords;
+
+		fn coords(&self) -> Self::Coords {
+			Coords(self.pos)
+		}
+
+		fn auto_id(&self) -> AutoTileId {
+			TileId::new(self.group_id, 0).into()
+		}
+
+		fn can_match(&self, other: &Self) -> bool {
+			self.auto_id() == other.auto_id()
+		}
+
+		fn is_decorative(&self) -> bool {
+			self.is_decorative
+		}
+	}
+
+	/// A simple square-grid tilemap
+	#[derive(Default)]
+	struct Tilemap(HashMap<IVec2, Tile>);
+
+	impl Tilemap {
+		fn place(&mut self, x: i32, y: i32, group_id: TileGroupId) -> Tile {
+			let tile = Tile {
+				pos: IVec2::new(x, y),
+				group_id,
+				is_decorative: false,
+			};
+			self.0.insert(tile.pos, tile);
+			tile
+		}
+	}
+
+	impl AutoTilemap for Tilemap {
+		type Tile = Tile;
+
+		fn make_coords(&self, pos: IVec2, _template: &Coords) -> Coords {
+			Coords(pos)
+		}
+
+		fn get_tile_at(&self, coords: &Coords) -> Option<Self::Tile> {
+			self.0.get(&coords.0).copied()
+		}
+
+		fn len(&self) -> usize {
+			self.0.len()
+		}
+	}
+
+	/// Runs the auto tiler for the given tile and returns the generated requests
+	fn process(
+		tilemap: &mut Tilemap,
+		tile: Tile,
+		include_self: bool,
+	) -> Vec<AutoTileRequest<Tile>> {
+		let mut tiler = AutoTiler::new(tilemap);
+		tiler.add_tile(tile, include_self);
+		tiler.finish()
+	}
+
+	/// Finds the rule requested for the tile at the given position
+	fn rule_at(requests: &[AutoTileRequest<Tile>], x: i32, y: i32) -> Option<AutoTileRule> {
+		requests
+			.iter()
+			.find(|request| request.tile.pos == IVec2::new(x, y))
+			.map(|request| request.rule)
+	}
+
+	#[test]
+	fn should_request_single_tile() {
+		let mut tilemap = Tilemap::default();
+		let tile = tilemap.place(0, 0, 0);
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(1, requests.len());
+		assert_eq!(Some(AutoTileRule::default()), rule_at(&requests, 0, 0));
+	}
+
+	#[test]
+	fn should_update_neighbors() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(0, 0, 0);
+		let tile = tilemap.place(1, 0, 0);
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(2, requests.len());
+		assert_eq!(
+			Some(AutoTileRule {
+				west: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 1, 0)
+		);
+		assert_eq!(
+			Some(AutoTileRule {
+				east: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 0, 0)
+		);
+	}
+
+	#[test]
+	fn should_detect_diagonals() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(1, 1, 0);
+		tilemap.place(-1, 1, 0);
+		tilemap.place(1, -1, 0);
+		tilemap.place(-1, -1, 0);
+		let tile = tilemap.place(0, 0, 0);
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(
+			Some(AutoTileRule {
+				north_east: Some(true),
+				north_west: Some(true),
+				south_east: Some(true),
+				south_west: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 0, 0)
+		);
+		assert_eq!(
+			Some(AutoTileRule {
+				south_west: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 1, 1)
+		);
+		assert_eq!(
+			Some(AutoTileRule {
+				north_east: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, -1, -1)
+		);
+	}
+
+	#[test]
+	fn should_handle_negative_coords() {
+		let mut tilemap = Tilemap::default();
+		for x in -6..=-4 {
+			for y in -6..=-4 {
+				tilemap.place(x, y, 0);
+			}
+		}
+		let tile = tilemap.0[&IVec2::new(-5, -5)];
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(9, requests.len());
+		assert_eq!(
+			Some(AutoTileRule {
+				north: Some(true),
+				east: Some(true),
+				south: Some(true),
+				west: Some(true),
+				north_east: Some(true),
+				north_west: Some(true),
+				south_east: Some(true),
+				south_west: Some(true),
+			}),
+			rule_at(&requests, -5, -5)
+		);
+		assert_eq!(
+			Some(AutoTileRule {
+				north: Some(true),
+				east: Some(true),
+				north_east: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, -6, -6)
+		);
+	}
+
+	#[test]
+	fn should_ignore_unmatched_neighbors() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(0, 1, 1);
+		tilemap.place(1, 0, 0);
+		let tile = tilemap.place(0, 0, 0);
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(2, requests.len());
+		assert_eq!(None, rule_at(&requests, 0, 1));
+		assert_eq!(
+			Some(AutoTileRule {
+				east: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 0, 0)
+		);
+	}
+
+	#[test]
+	fn should_compute_rule_without_requests() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(0, 1, 0);
+		tilemap.place(1, 0, 1);
+		let tile = Tile {
+			pos: IVec2::ZERO,
+			group_id: 0,
+			is_decorative: false,
+		};
+
+		let mut tiler = AutoTiler::new(&mut tilemap);
+		assert_eq!(
+			AutoTileRule {
+				north: Some(true),
+				..Default::default()
+			},
+			tiler.compute_rule(&tile)
+		);
+		assert!(tiler.finish().is_empty());
+	}
+
+	#[test]
+	fn should_ignore_decorative_neighbors() {
+		let mut tilemap = Tilemap::default();
+		let decoration = tilemap.place(0, 1, 0);
+		tilemap.0.insert(
+			decoration.pos,
+			Tile {
+				is_decorative: true,
+				..decoration
+			},
+		);
+		let tile = tilemap.place(0, 0, 0);
+
+		let requests = process(&mut tilemap, tile, true);
+		assert_eq!(2, requests.len());
+		// The decoration doesn't count as a connection for others...
+		assert_eq!(Some(AutoTileRule::default()), rule_at(&requests, 0, 0));
+		// ...but is still auto tiled itself
+		assert_eq!(
+			Some(AutoTileRule {
+				south: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 0, 1)
+		);
+	}
+
+	#[test]
+	fn should_recompute_all_tiles_once() {
+		let mut tilemap = Tilemap::default();
+		let tiles = vec![
+			tilemap.place(0, 0, 0),
+			tilemap.place(1, 0, 0),
+			tilemap.place(0, 1, 0),
+		];
+
+		let mut tiler = AutoTiler::new(&mut tilemap);
+		tiler.add_tiles(tiles);
+		let requests = tiler.finish();
+
+		assert_eq!(3, requests.len());
+		assert_eq!(
+			Some(AutoTileRule {
+				north: Some(true),
+				east: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 0, 0)
+		);
+	}
+
+	#[test]
+	fn should_request_each_tile_once() {
+		let mut tilemap = Tilemap::default();
+		let mut tiles = Vec::new();
+		for x in 0..3 {
+			for y in 0..3 {
+				tiles.push(tilemap.place(x, y, 0));
+			}
+		}
+		tiles.push(tilemap.place(3, 0, 0));
+
+		let mut tiler = AutoTiler::new(&mut tilemap);
+		tiler.add_tiles(tiles.clone());
+		let requests = tiler.finish();
+
+		assert_eq!(tiles.len(), requests.len());
+		for tile in &tiles {
+			let count = requests
+				.iter()
+				.filter(|request| request.tile.pos == tile.pos)
+				.count();
+			assert_eq!(1, count, "tile at {} was not requested once", tile.pos);
+		}
+	}
+
+	#[test]
+	fn should_update_neighbors_of_requested_tiles() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(2, 0, 0);
+		let a = tilemap.place(0, 0, 0);
+		let b = tilemap.place(1, 0, 0);
+
+		// `b` is requested as a neighbor of `a`, but its own neighbors still need updating
+		let mut tiler = AutoTiler::new(&mut tilemap);
+		tiler.add_tile(a, true);
+		tiler.add_tile(b, true);
+		let requests = tiler.finish();
+
+		assert_eq!(3, requests.len());
+		assert_eq!(
+			Some(AutoTileRule {
+				west: Some(true),
+				..Default::default()
+			}),
+			rule_at(&requests, 2, 0)
+		);
+	}
+
+	#[test]
+	fn should_require_cardinals_for_blob_corners() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(1, 1, 0);
+		tilemap.place(-1, -1, 0);
+		tilemap.place(-1, 0, 0);
+		tilemap.place(0, -1, 0);
+		let tile = Tile {
+			pos: IVec2::ZERO,
+			group_id: 0,
+			is_decorative: false,
+		};
+
+		let mut tiler = AutoTiler::new(&mut tilemap).with_mode(RuleGenerationMode::BlobCorners);
+		assert_eq!(
+			AutoTileRule {
+				south: Some(true),
+				west: Some(true),
+				south_west: Some(true),
+				..Default::default()
+			},
+			tiler.compute_rule(&tile)
+		);
+	}
+
+	#[test]
+	fn should_select_inner_corners() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(0, 1, 0);
+		tilemap.place(1, 0, 0);
+		tilemap.place(-1, 0, 0);
+		tilemap.place(-1, 1, 0);
+		let tile = Tile {
+			pos: IVec2::ZERO,
+			group_id: 0,
+			is_decorative: false,
+		};
+
+		let mut tiler = AutoTiler::new(&mut tilemap).with_mode(RuleGenerationMode::BlobCorners);
+		let rule = tiler.compute_rule(&tile);
+
+		let solid = AutoTileRule {
+			north: Some(true),
+			east: Some(true),
+			north_east: Some(true),
+			..Default::default()
+		};
+		let inner_corner = AutoTileRule {
+			north_east: Some(false),
+			..solid
+		};
+		assert!(!solid.is_subset_of(&rule));
+		assert!(inner_corner.is_subset_of(&rule));
+
+		// Filling in the corner selects the solid tile instead
+		tilemap.place(1, 1, 0);
+		let mut tiler = AutoTiler::new(&mut tilemap).with_mode(RuleGenerationMode::BlobCorners);
+		let rule = tiler.compute_rule(&tile);
+		assert!(solid.is_subset_of(&rule));
+		assert!(!inner_corner.is_subset_of(&rule));
+	}
+
+	#[test]
+	fn should_only_update_neighbors_on_removal() {
+		let mut tilemap = Tilemap::default();
+		tilemap.place(1, 0, 0);
+		let tile = tilemap.place(0, 0, 0);
+		tilemap.0.remove(&tile.pos);
+
+		let requests = process(&mut tilemap, tile, false);
+		assert_eq!(1, requests.len());
+		assert_eq!(None, rule_at(&requests, 0, 0));
+		assert_eq!(Some(AutoTileRule::default()), rule_at(&requests, 1, 0));
+	}
+}
@@ -1,22 +1,66 @@
 //! Types and tools for handling auto tiling
 
 use bevy::prelude::Component;
+use std::hash::{Hash, Hasher};
 
-pub use auto_tiler::AutoTiler;
+pub use auto_tiler::{AutoTiler, RuleGenerationMode};
+pub use resolve::resolve_requests;
 pub use traits::{AutoTile, AutoTileRequest, AutoTilemap};
 
 use crate::ids::{TileGroupId, TileId, TilesetId};
 
 mod auto_tiler;
+mod resolve;
 mod traits;
 
 /// A component used to ID an Auto Tile
 ///
-/// This should be attached to every tile that wishes to participate in some type of auto tiling
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+/// This should be attached to every tile that wishes to participate in some type of auto tiling.
+///
+/// Two IDs are considered equal if they share the same group and tileset. The chosen
+/// `variant_index` does not take part in comparisons or hashing.
+#[derive(Debug, Copy, Clone, Component)]
 pub struct AutoTileId {
 	pub group_id: TileGroupId,
 	pub tileset_id: TilesetId,
+	/// The index of the variant last chosen for this tile (if any)
+	///
+	/// When set, the same variant will be chosen again whenever the tile is re-evaluated (as long as
+	/// the matching auto tile has a variant at this index). This keeps auto tiles with multiple variants
+	/// from visually changing when their neighbors are updated.
+	pub variant_index: Option<usize>,
+}
+
+impl AutoTileId {
+	/// Create a new auto tile ID without a chosen variant
+	pub const fn new(group_id: TileGroupId, tileset_id: TilesetId) -> Self {
+		Self {
+			group_id,
+			tileset_id,
+			variant_index: None,
+		}
+	}
+
+	/// Sets the variant index of this ID
+	pub fn variant(mut self, index: usize) -> Self {
+		self.variant_index = Some(index);
+		self
+	}
+}
+
+impl PartialEq for AutoTileId {
+	fn eq(&self, other: &Self) -> bool {
+		self.group_id == other.group_id && self.tileset_id == other.tileset_id
+	}
+}
+
+impl Eq for AutoTileId {}
+
+impl Hash for AutoTileId {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.group_id.hash(state);
+		self.tileset_id.hash(state);
+	}
 }
 
 impl From<TileId> for AutoTileId {
@@ -24,12 +68,16 @@ impl From<TileId> for AutoTileId {
 		Self {
 			group_id: id.group_id,
 			tileset_id: id.tileset_id,
+			variant_index: id.variant_index,
 		}
 	}
 }
 
 impl From<AutoTileId> for TileId {
 	fn from(id: AutoTileId) -> Self {
-		Self::new(id.group_id, id.tileset_id)
+		Self {
+			variant_index: id.variant_index,
+			..Self::new(id.group_id, id.tileset_id)
+		}
 	}
 }
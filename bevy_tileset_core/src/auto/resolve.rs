@@ -0,0 +1,64 @@
+use crate::auto::{AutoTile, AutoTileId, AutoTileRequest};
+use crate::prelude::{TileIndex, Tilesets};
+
+/// Resolves the given auto tile requests into the [`TileIndex`] each tile should be updated to
+///
+/// Each index is returned alongside the resolved [`AutoTileId`], which contains the chosen
+/// `variant_index`. This ID should be stored on the tile so that the same variant is chosen
+/// the next time it is evaluated.
+///
+/// This is the shared "request → index" step that every tilemap integration needs after
+/// calling [`AutoTiler::finish`](crate::auto::AutoTiler::finish).
+///
+/// Requests are skipped (i.e. not included in the output) if:
+/// * The tile's tileset is not loaded or does not contain the tile
+/// * No auto tile matches the requested rule
+/// * The tile's current [`texture_index`](AutoTile::texture_index) is already a variant of the
+///   matching auto tile (see [`Tileset::is_auto_variant`](crate::prelude::Tileset::is_auto_variant)),
+///   which keeps tiles from swapping variants whenever a neighbor changes
+///
+/// # Arguments
+///
+/// * `requests`: The requests to resolve
+/// * `tilesets`: The loaded tilesets
+///
+/// returns: Vec<(T, TileIndex, AutoTileId)>
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_tileset_core::auto::{resolve_requests, AutoTiler, AutoTilemap};
+/// # use bevy_tileset_core::prelude::*;
+/// fn update_tiles<M: AutoTilemap>(auto_tiler: AutoTiler<M>, tilesets: &Tilesets)
+/// where
+/// 	M::Tile: Clone,
+/// {
+/// 	let requests = auto_tiler.finish();
+/// 	for (tile, index, id) in resolve_requests(&requests, tilesets) {
+/// 		// Update `tile` to use `index` and store `id`...
+/// 	}
+/// }
+/// ```
+pub fn resolve_requests<T: AutoTile + Clone>(
+	requests: &[AutoTileRequest<T>],
+	tilesets: &Tilesets,
+) -> Vec<(T, TileIndex, AutoTileId)> {
+	requests
+		.iter()
+		.filter_map(|request| {
+			let id = request.tile.auto_id();
+			let tileset = tilesets.get_by_id(&id.tileset_id)?;
+
+			if let Some(index) = request.tile.texture_index() {
+				let name = tileset.get_tile_name(&id.group_id)?;
+				if tileset.is_auto_variant(name, &index, &request.rule) {
+					// Tile already matches the rule
+					return None;
+				}
+			}
+
+			let (index, id) = tileset.resolve_auto_tile(&id, request.rule)?;
+			Some((request.tile.clone(), index, id))
+		})
+		.collect()
+}
@@ -35,6 +35,20 @@ pub trait AutoTile {
 	/// This is what allows auto tiles to be compared against one another. If, for example, you want tiles to
 	/// only match within their layer, make sure you add a check ensuring that the two tiles are on the same layer.
 	fn can_match(&self, other: &Self) -> bool;
+	/// Returns whether or not this tile is purely decorative
+	///
+	/// Decorative tiles are still auto tiled themselves, but they are ignored when generating the rules
+	/// of their neighbors (i.e. they never count as a connection). By default, no tile is decorative.
+	fn is_decorative(&self) -> bool {
+		false
+	}
+	/// Get the tile's current texture index (if known)
+	///
+	/// This is used by [`resolve_requests`](crate::auto::resolve_requests) to skip tiles that already
+	/// match their requested rule. By default, the index is unknown and tiles are never skipped.
+	fn texture_index(&self) -> Option<usize> {
+		None
+	}
 	/// Get the tile's current position in the tilemap
 	fn pos(&self) -> IVec2 {
 		self.coords().pos()
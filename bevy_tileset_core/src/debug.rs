@@ -1,9 +1,11 @@
 //! Used for debugging tilesets
 
 use bevy::app::App;
-use bevy::math::Vec3;
-use bevy::prelude::{Commands, Component, Local, Plugin, Transform, Update};
-use bevy::sprite::SpriteBundle;
+use bevy::math::{Vec2, Vec3};
+use bevy::prelude::{
+	BuildChildren, ChildBuilder, Color, Commands, Component, Local, Plugin, Transform, Update,
+};
+use bevy::sprite::{Sprite, SpriteBundle};
 
 use crate::prelude::{Tileset, Tilesets};
 
@@ -24,6 +26,14 @@ pub struct DebugTilesetPlugin {
 	///
 	/// If `None`, displays at the world origin
 	pub position: Vec3,
+	/// The color of the lines drawn between each cell of the atlas
+	///
+	/// If `None`, no grid is drawn
+	pub grid_color: Option<Color>,
+	/// The color drawn behind the atlas
+	///
+	/// If `None`, no background is drawn
+	pub background_color: Option<Color>,
 }
 
 impl Plugin for DebugTilesetPlugin {
@@ -31,6 +41,8 @@ impl Plugin for DebugTilesetPlugin {
 		let state = DebugState {
 			name: self.tileset_name.clone(),
 			position: self.position,
+			grid_color: self.grid_color,
+			background_color: self.background_color,
 		};
 		app.add_systems(Update, display_tilesets(state));
 	}
@@ -65,6 +77,7 @@ impl DebugTilesetPlugin {
 		Self {
 			tileset_name: Some(tileset_name.to_string()),
 			position,
+			..Default::default()
 		}
 	}
 
@@ -80,14 +93,45 @@ impl DebugTilesetPlugin {
 		Self {
 			tileset_name: None,
 			position,
+			..Default::default()
 		}
 	}
+
+	/// Draws lines between each cell of the atlas
+	///
+	/// This makes it easier to count indices and spot transparent tiles.
+	///
+	/// # Arguments
+	///
+	/// * `color`: The color of the lines
+	///
+	/// returns: DebugTilesetPlugin
+	///
+	pub fn with_grid(mut self, color: Color) -> Self {
+		self.grid_color = Some(color);
+		self
+	}
+
+	/// Draws a solid background behind the atlas
+	///
+	/// # Arguments
+	///
+	/// * `color`: The color of the background
+	///
+	/// returns: DebugTilesetPlugin
+	///
+	pub fn with_background(mut self, color: Color) -> Self {
+		self.background_color = Some(color);
+		self
+	}
 }
 
 #[derive(Default)]
 struct DebugState {
 	name: Option<String>,
 	position: Vec3,
+	grid_color: Option<Color>,
+	background_color: Option<Color>,
 }
 
 fn display_tilesets(state: DebugState) -> impl FnMut(Local<bool>, Tilesets, Commands) {
@@ -106,7 +150,15 @@ fn display_tilesets(state: DebugState) -> impl FnMut(Local<bool>, Tilesets, Comm
 					transform: Transform::from_translation(state.position + offset),
 					..Default::default()
 				})
-				.insert(DebugTilesetSprite);
+				.insert(DebugTilesetSprite)
+				.with_children(|parent| {
+					if let Some(color) = state.background_color {
+						spawn_rect(parent, color, Vec2::ZERO, tileset.size(), -0.1);
+					}
+					if let Some(color) = state.grid_color {
+						spawn_grid(parent, color, tileset);
+					}
+				});
 
 			offset.y -= tileset.size().y + PADDING;
 			*is_loaded = true;
@@ -125,3 +177,38 @@ fn display_tilesets(state: DebugState) -> impl FnMut(Local<bool>, Tilesets, Comm
 		}
 	}
 }
+
+/// Spawns lines along the edges of each cell in the given tileset's atlas
+fn spawn_grid(parent: &mut ChildBuilder, color: Color, tileset: &Tileset) {
+	const THICKNESS: f32 = 1.0;
+
+	let size = tileset.size();
+	let tile_size = tileset.tile_size().max(Vec2::ONE);
+	let columns = (size.x / tile_size.x) as usize;
+	let rows = (size.y / tile_size.y) as usize;
+	let half_size = size / 2.0;
+
+	for column in 0..=columns {
+		let x = column as f32 * tile_size.x - half_size.x;
+		let line_size = Vec2::new(THICKNESS, size.y);
+		spawn_rect(parent, color, Vec2::new(x, 0.0), line_size, 0.1);
+	}
+	for row in 0..=rows {
+		let y = half_size.y - row as f32 * tile_size.y;
+		let line_size = Vec2::new(size.x, THICKNESS);
+		spawn_rect(parent, color, Vec2::new(0.0, y), line_size, 0.1);
+	}
+}
+
+/// Spawns a solid rectangle relative to its parent
+fn spawn_rect(parent: &mut ChildBuilder, color: Color, position: Vec2, size: Vec2, z: f32) {
+	parent.spawn(SpriteBundle {
+		sprite: Sprite {
+			color,
+			custom_size: Some(size),
+			..Default::default()
+		},
+		transform: Transform::from_translation(position.extend(z)),
+		..Default::default()
+	});
+}
@@ -54,6 +54,39 @@ impl TileId {
 		}
 	}
 
+	/// Sets the variant index of this ID
+	///
+	/// Does nothing if the `variants` feature is disabled.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// // Variant 2 of group 5
+	/// let id = TileId::new(5, 0).variant(2);
+	/// ```
+	#[cfg_attr(not(feature = "variants"), allow(unused_mut, unused_variables))]
+	pub fn variant(mut self, index: usize) -> Self {
+		#[cfg(feature = "variants")]
+		{
+			self.variant_index = Some(index);
+		}
+		self
+	}
+
+	/// Sets the auto index of this ID
+	///
+	/// Does nothing if the `auto-tile` feature is disabled.
+	#[cfg_attr(not(feature = "auto-tile"), allow(unused_mut, unused_variables))]
+	pub fn auto(mut self, index: usize) -> Self {
+		#[cfg(feature = "auto-tile")]
+		{
+			self.auto_index = Some(index);
+		}
+		self
+	}
+
 	/// Returns true if two tiles are of the same variant, auto tile, group, and tileset
 	#[cfg(feature = "variants")]
 	pub fn eq_variant(&self, other: &TileId) -> bool {
@@ -99,6 +132,39 @@ impl PartialTileId {
 		}
 	}
 
+	/// Sets the variant index of this ID
+	///
+	/// Does nothing if the `variants` feature is disabled.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// // Variant 2 of group 5
+	/// let id = PartialTileId::new(5).variant(2);
+	/// ```
+	#[cfg_attr(not(feature = "variants"), allow(unused_mut, unused_variables))]
+	pub fn variant(mut self, index: usize) -> Self {
+		#[cfg(feature = "variants")]
+		{
+			self.variant_index = Some(index);
+		}
+		self
+	}
+
+	/// Sets the auto index of this ID
+	///
+	/// Does nothing if the `auto-tile` feature is disabled.
+	#[cfg_attr(not(feature = "auto-tile"), allow(unused_mut, unused_variables))]
+	pub fn auto(mut self, index: usize) -> Self {
+		#[cfg(feature = "auto-tile")]
+		{
+			self.auto_index = Some(index);
+		}
+		self
+	}
+
 	/// Extends this [`PartialTileId`] into a full [`TileId`]
 	pub fn extend(self, tileset_id: TilesetId) -> TileId {
 		TileId {
@@ -1,16 +1,73 @@
-use crate::tileset::{Tileset, TilesetAssetLoader, TilesetMap};
+use crate::tileset::{TileAliases, Tileset, TilesetAssetLoader, TilesetHandles, TilesetMap};
 use bevy::prelude::*;
 
 /// Plugin for setting up tilesets
-#[derive(Default)]
-pub struct TilesetPlugin {}
+pub struct TilesetPlugin {
+	extensions: &'static [&'static str],
+}
+
+impl Default for TilesetPlugin {
+	fn default() -> Self {
+		Self {
+			extensions: TilesetAssetLoader::DEFAULT_EXTENSIONS,
+		}
+	}
+}
+
+impl TilesetPlugin {
+	/// Only load tileset configuration files with the given extensions
+	///
+	/// By default, every `.ron` file is treated as a tileset. Projects that use RON for other
+	/// assets can use a dedicated extension (such as `"tileset.ron"`) to avoid the tileset loader
+	/// picking up unrelated files. Tile definitions are read directly by the tileset loader, so
+	/// they are not affected by this.
+	///
+	/// # Arguments
+	///
+	/// * `extensions`: The extensions to register (without the leading dot)
+	///
+	/// returns: TilesetPlugin
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// App::new().add_plugins(TilesetPlugin::default().with_extensions(&["tileset.ron"]));
+	/// ```
+	pub fn with_extensions(mut self, extensions: &'static [&'static str]) -> Self {
+		self.extensions = extensions;
+		self
+	}
+}
+
+/// The system sets used by the [`TilesetPlugin`]
+///
+/// These can be used to order your own systems relative to the ones added by this crate
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TilesetSet {
+	/// Registers (and deregisters) tilesets as they are loaded (and unloaded), making them
+	/// available to the [`Tilesets`](crate::prelude::Tilesets) system param
+	RegisterTilesets,
+}
 
 impl Plugin for TilesetPlugin {
 	fn build(&self, app: &mut App) {
+		let loader =
+			TilesetAssetLoader::from_world(&mut app.world).with_extensions(self.extensions);
 		app.add_asset::<Tileset>()
-			.init_asset_loader::<TilesetAssetLoader>()
+			.add_asset_loader(loader)
 			.init_resource::<TilesetMap>()
-			.add_systems(Update, tileset_event_sys);
+			.init_resource::<TilesetHandles>()
+			.init_resource::<TileAliases>()
+			.add_systems(
+				Update,
+				tileset_event_sys.in_set(TilesetSet::RegisterTilesets),
+			);
+
+		#[cfg(feature = "variants")]
+		app.init_resource::<crate::tileset::TilesetRng>();
 	}
 }
 
@@ -24,13 +81,19 @@ fn tileset_event_sys(
 		match event {
 			AssetEvent::<Tileset>::Created { handle } => {
 				if let Some(tileset) = tilesets.get(handle) {
-					map.register_tileset(tileset, &handle);
+					map.register_tileset(tileset, &tilesets.get_handle(handle));
+				}
+			},
+			AssetEvent::<Tileset>::Modified { handle } => {
+				// The tileset's name or ID may have changed, so make sure no stale entries remain
+				map.deregister_tileset(handle);
+				if let Some(tileset) = tilesets.get(handle) {
+					map.register_tileset(tileset, &tilesets.get_handle(handle));
 				}
 			},
 			AssetEvent::<Tileset>::Removed { handle } => {
 				map.deregister_tileset(&handle);
 			},
-			_ => {},
 		}
 	}
 }
@@ -1,23 +1,68 @@
 use std::collections::{BTreeMap, HashMap};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
 use bevy::asset::{
 	Asset, AssetLoader, AssetPath, BoxedFuture, Handle, HandleId, LoadContext, LoadedAsset,
 };
-use bevy::prelude::{FromWorld, World};
+use bevy::prelude::{FromWorld, Resource, UVec2, World};
 use bevy::render::renderer::RenderDevice;
 use bevy::render::texture::{CompressedImageFormats, Image, ImageType};
+use bevy::tasks::AsyncComputeTaskPool;
 use bevy::utils::Uuid;
 use bevy_tile_atlas::TextureStore;
-use bevy_tileset_tiles::prelude::{TileDef, TileHandle};
+use bevy_tileset_tiles::prelude::{PartialTileDef, TileDef, TileHandle};
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::{TileGroupId, Tileset, TilesetBuilder, TilesetError, TilesetId};
-use crate::tileset::load::{load_tile_handles, TextureLoader};
+use crate::prelude::{
+	TileGroupId, Tileset, TilesetBuilder, TilesetError, TilesetId, TilesetMigrations,
+};
+use crate::tileset::builder::slice_sheet;
+use crate::tileset::load::{
+	complete_tile_def, load_tile_handles, parse_partial_tile_def, parse_tile_def,
+	parse_tileset_def, TextureLoader,
+};
 
+/// The asset loader for [`Tileset`] configuration files
+///
+/// This is added automatically by the [`TilesetPlugin`](crate::prelude::TilesetPlugin).
 pub struct TilesetAssetLoader {
 	supported_compressed_formats: CompressedImageFormats,
+	settings: TilesetLoadSettings,
+	extensions: &'static [&'static str],
+}
+
+/// A resource containing the settings used when loading tileset assets
+///
+/// This is shared with the [`TilesetAssetLoader`], so any changes made to it will apply to every
+/// tileset loaded afterwards.
+///
+/// # Variables
+///
+/// Paths within tileset and tile definitions may contain variables in the form `${NAME}`. These are
+/// replaced with the value registered under `NAME` before the file is loaded. Variables that have not
+/// been registered are left as-is, so no expansion happens by default.
+///
+/// # Migrations
+///
+/// Old definition files can be upgraded to the current schema as they are loaded by registering
+/// migrations (see [`TilesetMigrations`]).
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tileset_core::prelude::*;
+///
+/// fn set_theme(settings: Res<TilesetLoadSettings>) {
+/// 	// "textures/${THEME}/wall.png" -> "textures/winter/wall.png"
+/// 	settings.set_var("THEME", "winter");
+/// }
+/// ```
+#[derive(Resource, Clone, Default)]
+pub struct TilesetLoadSettings {
+	vars: Arc<RwLock<HashMap<String, String>>>,
+	migrations: TilesetMigrations,
 }
 
 #[derive(Default, Deserialize, Serialize)]
@@ -27,8 +72,130 @@ pub struct TilesetDef {
 	/// The ID of the tileset
 	pub id: TilesetId,
 	/// The tiles in this tileset as a mapping of their group ID to the relative path to
-	/// their definition file
-	pub tiles: BTreeMap<TileGroupId, String>,
+	/// their definition file (see [`TilesetEntry`])
+	pub tiles: BTreeMap<TileGroupId, TilesetEntry>,
+	/// The group ID of the tile used to represent an empty cell (if any)
+	///
+	/// Loading fails if this is not the group ID of one of the tiles.
+	#[serde(default)]
+	pub empty: Option<TileGroupId>,
+	/// A single image to slice into standard tiles (if any)
+	#[serde(default)]
+	pub sheet: Option<TilesetSheetDef>,
+}
+
+/// A tile entry in a [`TilesetDef`]
+///
+/// This is either just the path to the tile's definition file, or the path along with a name
+/// that overrides the one given in the definition file. The latter allows a single definition file
+/// to be registered multiple times under different names.
+///
+/// # Examples
+///
+/// ```ron
+/// (
+/// 	// ...
+/// 	tiles: {
+/// 		0: "../tiles/grass.ron",
+/// 		1: (path: "../tiles/grass.ron", name: "Dry Grass"),
+/// 	},
+/// )
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TilesetEntry {
+	/// The relative path to the tile's definition file
+	Path(String),
+	/// The relative path to the tile's definition file along with the tile's name
+	Named { path: String, name: String },
+}
+
+impl TilesetEntry {
+	/// Gets the relative path to the tile's definition file
+	pub fn path(&self) -> &str {
+		match self {
+			Self::Path(path) => path,
+			Self::Named { path, .. } => path,
+		}
+	}
+
+	/// Gets the name that overrides the one in the tile's definition file (if any)
+	pub fn name(&self) -> Option<&str> {
+		match self {
+			Self::Path(..) => None,
+			Self::Named { name, .. } => Some(name),
+		}
+	}
+}
+
+impl From<String> for TilesetEntry {
+	fn from(path: String) -> Self {
+		Self::Path(path)
+	}
+}
+
+/// A single image containing a grid of tiles
+///
+/// Each cell in the grid is added as a standard tile. Cells are read left-to-right, top-to-bottom,
+/// starting at the top-left corner.
+///
+/// # Examples
+///
+/// ```ron
+/// (
+/// 	// ...
+/// 	sheet: Some((
+/// 		path: "../textures/tilesheet.png",
+/// 		grid: (16, 16),
+/// 		names: {
+/// 			0: "Grass",
+/// 			1: "Dirt",
+/// 		},
+/// 	)),
+/// )
+/// ```
+#[derive(Default, Deserialize, Serialize)]
+pub struct TilesetSheetDef {
+	/// The path to the image relative to the tileset's configuration file
+	pub path: String,
+	/// The number of columns and rows in the image
+	pub grid: UVec2,
+	/// The group ID of the first cell
+	///
+	/// Each subsequent cell is given the next group ID.
+	///
+	/// Default: 0
+	#[serde(default)]
+	pub first_id: TileGroupId,
+	/// The tile names mapped by their cell index
+	///
+	/// Any cell not given a name will be named `tile_{index}`.
+	#[serde(default)]
+	pub names: BTreeMap<usize, String>,
+}
+
+impl TilesetSheetDef {
+	/// Get the number of cells in the grid
+	///
+	/// Fails if the grid is empty or if its cells cannot all be given a group ID.
+	fn cell_count(&self) -> Result<TileGroupId, TilesetError> {
+		let invalid = || TilesetError::InvalidData {
+			expected: String::from("A non-empty sheet grid with a group ID for every cell"),
+			found: format!(
+				"A {}x{} grid starting at group ID {}",
+				self.grid.x, self.grid.y, self.first_id
+			),
+		};
+
+		let count = self
+			.grid
+			.x
+			.checked_mul(self.grid.y)
+			.filter(|count| *count > 0)
+			.ok_or_else(invalid)?;
+		self.first_id.checked_add(count - 1).ok_or_else(invalid)?;
+		Ok(count)
+	}
 }
 
 /// A struct that mimics a Bevy `AssetServer`
@@ -37,20 +204,34 @@ pub struct TilesetDef {
 /// later (so we don't need to await on _every_ image).
 struct TilesetTextureLoader<'x, 'y> {
 	supported_compressed_formats: CompressedImageFormats,
+	settings: &'x TilesetLoadSettings,
 	load_context: &'x mut LoadContext<'y>,
 	/// The images that need to be loaded
 	bytes: Arc<RwLock<HashMap<HandleId, PathBuf>>>,
 }
 
+/// A [`TextureLoader`] that replaces the registered variables in each path before loading it
+struct ExpandingLoader<'x, TLoader: TextureLoader> {
+	settings: &'x TilesetLoadSettings,
+	loader: &'x TLoader,
+}
+
 /// A struct that mimics a Bevy `Assets<Texture>` resource by allowing get/add operations
 struct TilesetTextureStore<'x, 'y> {
 	load_context: &'x mut LoadContext<'y>,
 	images: HashMap<HandleId, Image>,
 }
 
+impl<'x, TLoader: TextureLoader> TextureLoader for ExpandingLoader<'x, TLoader> {
+	fn load_texture<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<Image> {
+		let asset_path = self.settings.expand_asset_path(path.into());
+		self.loader.load_texture::<T, _>(asset_path)
+	}
+}
+
 impl<'x, 'y> TextureLoader for TilesetTextureLoader<'x, 'y> {
 	fn load_texture<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<Image> {
-		let asset_path = path.into().clone();
+		let asset_path = path.into();
 		let handle: Handle<Image> = self.load_context.get_handle(asset_path.clone());
 		let path = asset_path.path().to_path_buf();
 
@@ -103,17 +284,233 @@ impl<'x, 'y> TextureStore for TilesetTextureStore<'x, 'y> {
 	}
 }
 
-impl FromWorld for TilesetAssetLoader {
-	fn from_world(world: &mut World) -> Self {
-		let supported_compressed_formats = match world.get_resource::<RenderDevice>() {
-			Some(render_device) => CompressedImageFormats::from_features(render_device.features()),
+impl TilesetLoadSettings {
+	/// Set the value of a path variable
+	///
+	/// # Arguments
+	///
+	/// * `name`: The name of the variable (without the surrounding `${}`)
+	/// * `value`: The value to replace the variable with
+	///
+	/// returns: Option<String>
+	///
+	/// Returns the previous value of the variable (if any).
+	pub fn set_var(&self, name: &str, value: &str) -> Option<String> {
+		self.vars
+			.write()
+			.unwrap()
+			.insert(name.to_string(), value.to_string())
+	}
+
+	/// Remove a path variable
+	///
+	/// # Arguments
+	///
+	/// * `name`: The name of the variable (without the surrounding `${}`)
+	///
+	/// returns: Option<String>
+	pub fn remove_var(&self, name: &str) -> Option<String> {
+		self.vars.write().unwrap().remove(name)
+	}
+
+	/// Get the migrations run on definition files before they are parsed
+	pub fn migrations(&self) -> &TilesetMigrations {
+		&self.migrations
+	}
+
+	/// Parse a [`TileDef`] from the bytes of its definition file, running any registered
+	/// migrations first
+	///
+	/// Unlike [`parse_tile_def`](crate::prelude::parse_tile_def), this upgrades the file just as
+	/// the asset loader would, so it should be preferred when loading definitions manually.
+	///
+	/// # Arguments
+	///
+	/// * `bytes`: The contents of the definition file
+	///
+	/// returns: Result<TileDef, TilesetError>
+	///
+	pub fn parse_tile_def(&self, bytes: &[u8]) -> Result<TileDef, TilesetError> {
+		parse_tile_def(&self.migrations.migrate_tile(bytes)?)
+	}
 
-			None => CompressedImageFormats::all(),
+	/// Parse a [`PartialTileDef`] from the bytes of its definition file, running any registered
+	/// migrations first (see [`parse_tile_def`](Self::parse_tile_def))
+	///
+	/// # Arguments
+	///
+	/// * `bytes`: The contents of the definition file
+	///
+	/// returns: Result<PartialTileDef, TilesetError>
+	///
+	pub fn parse_partial_tile_def(&self, bytes: &[u8]) -> Result<PartialTileDef, TilesetError> {
+		parse_partial_tile_def(&self.migrations.migrate_tile(bytes)?)
+	}
+
+	/// Parse a [`TilesetDef`] from the bytes of its definition file, running any registered
+	/// migrations first (see [`parse_tile_def`](Self::parse_tile_def))
+	///
+	/// # Arguments
+	///
+	/// * `bytes`: The contents of the definition file
+	///
+	/// returns: Result<TilesetDef, TilesetError>
+	///
+	pub fn parse_tileset_def(&self, bytes: &[u8]) -> Result<TilesetDef, TilesetError> {
+		parse_tileset_def(&self.migrations.migrate_tileset(bytes)?)
+	}
+
+	/// Load the intermediate tile handles from a set of tile definitions, replacing any registered
+	/// variables in their texture paths
+	///
+	/// This is the same as [`load_tile_handles`](crate::prelude::load_tile_handles), but resolves
+	/// paths just as the asset loader would, so it should be preferred when loading definitions manually.
+	///
+	/// # Arguments
+	///
+	/// * `tiles`: The collection of tile definitions (in their intended order)
+	/// * `asset_loader`: The `AssetServer` or other loader for loading the textures
+	///
+	/// returns: Vec<TileHandle>
+	///
+	pub fn load_tile_handles<TTiles: IntoIterator<Item = TileDef>, TLoader: TextureLoader>(
+		&self,
+		tiles: TTiles,
+		asset_loader: &TLoader,
+	) -> Vec<TileHandle> {
+		let loader = ExpandingLoader {
+			settings: self,
+			loader: asset_loader,
 		};
+		load_tile_handles(tiles, &loader)
+	}
+
+	/// Replace all registered variables in the given path with their values
+	///
+	/// # Arguments
+	///
+	/// * `path`: The path to expand
+	///
+	/// returns: String
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// let settings = TilesetLoadSettings::default();
+	/// settings.set_var("THEME", "winter");
+	///
+	/// assert_eq!("winter/wall.png", settings.expand("${THEME}/wall.png"));
+	/// assert_eq!("${OTHER}/wall.png", settings.expand("${OTHER}/wall.png"));
+	/// ```
+	pub fn expand(&self, path: &str) -> String {
+		let vars = self.vars.read().unwrap();
+		if vars.is_empty() {
+			return path.to_string();
+		}
+
+		let mut expanded = String::with_capacity(path.len());
+		let mut rest = path;
+		while let Some(start) = rest.find("${") {
+			let Some(len) = rest[start..].find('}') else {
+				break;
+			};
+			let name = &rest[start + 2..start + len];
+			expanded.push_str(&rest[..start]);
+			match vars.get(name) {
+				Some(value) => expanded.push_str(value),
+				None => expanded.push_str(&rest[start..=start + len]),
+			}
+			rest = &rest[start + len + 1..];
+		}
+		expanded.push_str(rest);
+		expanded
+	}
+
+	/// Expands the path of the given `AssetPath` (see [`expand`](Self::expand))
+	fn expand_asset_path(&self, asset_path: AssetPath) -> AssetPath<'static> {
+		let path = self.expand(&asset_path.path().to_string_lossy());
+		AssetPath::new(PathBuf::from(path), asset_path.label().map(String::from))
+	}
+}
+
+impl TilesetAssetLoader {
+	/// The file extensions a tileset loader is registered for by default
+	pub const DEFAULT_EXTENSIONS: &[&str] = &["ron"];
+
+	/// Create a loader that does not depend on a `RenderDevice`
+	///
+	/// All compressed image formats are assumed to be supported. This is useful for loading
+	/// tilesets in a headless `App` (such as in tests), where no renderer is available.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// App::new()
+	/// 	.add_plugins(MinimalPlugins)
+	/// 	.add_plugins(AssetPlugin::default())
+	/// 	.add_asset::<Tileset>()
+	/// 	.add_asset_loader(TilesetAssetLoader::headless());
+	/// ```
+	pub fn headless() -> Self {
 		Self {
-			supported_compressed_formats,
+			supported_compressed_formats: CompressedImageFormats::all(),
+			settings: TilesetLoadSettings::default(),
+			extensions: Self::DEFAULT_EXTENSIONS,
 		}
 	}
+
+	/// Use the given settings when loading tilesets
+	///
+	/// # Arguments
+	///
+	/// * `settings`: The settings to share with this loader
+	///
+	/// returns: TilesetAssetLoader
+	///
+	pub fn with_settings(mut self, settings: TilesetLoadSettings) -> Self {
+		self.settings = settings;
+		self
+	}
+
+	/// Register this loader for the given file extensions instead of [`DEFAULT_EXTENSIONS`](Self::DEFAULT_EXTENSIONS)
+	///
+	/// Extensions may contain multiple parts (such as `"tileset.ron"`), which allows tileset
+	/// configuration files to be told apart from other RON assets.
+	///
+	/// # Arguments
+	///
+	/// * `extensions`: The extensions to register (without the leading dot)
+	///
+	/// returns: TilesetAssetLoader
+	///
+	pub fn with_extensions(mut self, extensions: &'static [&'static str]) -> Self {
+		self.extensions = extensions;
+		self
+	}
+}
+
+impl FromWorld for TilesetAssetLoader {
+	fn from_world(world: &mut World) -> Self {
+		let settings = world
+			.get_resource_or_insert_with(TilesetLoadSettings::default)
+			.clone();
+		let loader = match world.get_resource::<RenderDevice>() {
+			Some(render_device) => Self {
+				supported_compressed_formats: CompressedImageFormats::from_features(
+					render_device.features(),
+				),
+				settings: TilesetLoadSettings::default(),
+				extensions: Self::DEFAULT_EXTENSIONS,
+			},
+			None => Self::headless(),
+		};
+		loader.with_settings(settings)
+	}
 }
 
 impl AssetLoader for TilesetAssetLoader {
@@ -123,11 +520,26 @@ impl AssetLoader for TilesetAssetLoader {
 		load_context: &'a mut LoadContext,
 	) -> BoxedFuture<'a, anyhow::Result<(), anyhow::Error>> {
 		Box::pin(async move {
-			let config = ron::de::from_bytes::<TilesetDef>(bytes)?;
+			let config = self.settings.parse_tileset_def(bytes)?;
+
+			let sheet_tiles = match config.sheet {
+				Some(ref sheet) => {
+					let sheet_path = self.settings.expand(&sheet.path);
+					load_sheet(
+						load_context,
+						sheet,
+						&sheet_path,
+						self.supported_compressed_formats,
+					)
+					.await?
+				},
+				None => Vec::new(),
+			};
 
 			// === Load Handles === //
 			let loader = TilesetTextureLoader {
 				supported_compressed_formats: self.supported_compressed_formats,
+				settings: &self.settings,
 				bytes: Arc::new(RwLock::new(HashMap::new())),
 				load_context,
 			};
@@ -142,9 +554,15 @@ impl AssetLoader for TilesetAssetLoader {
 			};
 
 			let mut builder = TilesetBuilder::default();
+			// The sheet is added first so that its cells are also its atlas indices,
+			// allowing animations to refer to them by their range
+			for (group_id, name, texture) in sheet_tiles {
+				builder.add_standard_image(name, group_id, texture)?;
+			}
 			for (group_id, tile_handle) in tile_handles {
 				builder.add_tile(tile_handle, group_id, &store)?;
 			}
+			builder.set_empty_tile(config.empty)?;
 
 			// === Create Raw Tileset === //
 			let name = config
@@ -166,6 +584,7 @@ impl AssetLoader for TilesetAssetLoader {
 				tile_names: raw_tileset.tile_names,
 				tile_handles: raw_tileset.tile_handles,
 				tile_indices: raw_tileset.tile_indices,
+				empty_tile: raw_tileset.empty_tile,
 				atlas,
 				texture,
 			};
@@ -177,53 +596,153 @@ impl AssetLoader for TilesetAssetLoader {
 	}
 
 	fn extensions(&self) -> &[&str] {
-		&["ron"]
+		self.extensions
 	}
 }
 
 /// Get a `Vec` of ([`TileGroupId`], [`TileHandle`]) tuples
 async fn get_tile_handles<'x, 'y>(
 	loader: &'x TilesetTextureLoader<'x, 'y>,
-	tile_paths: &BTreeMap<TileGroupId, String>,
+	tile_paths: &BTreeMap<TileGroupId, TilesetEntry>,
 ) -> Result<Vec<(TileGroupId, TileHandle)>, TilesetError> {
-	let tile_defs = futures::future::join_all(
-		tile_paths
-			.iter()
-			.map(|(.., tile_path)| load_tile(&loader.load_context, tile_path)),
-	)
-	.await
-	.into_iter()
-	.filter_map(|tile_def| tile_def.ok())
-	.collect::<Vec<_>>();
+	let (group_ids, tile_defs): (Vec<_>, Vec<_>) =
+		futures::future::join_all(tile_paths.iter().map(|(id, entry)| async move {
+			let def = load_tile(loader.load_context, loader.settings, entry).await;
+			(*id, def)
+		}))
+		.await
+		.into_iter()
+		// Skip any tiles that failed to load (while keeping the remaining IDs aligned with their tiles).
+		// Circular references are a mistake in the configuration itself, so they fail the entire load.
+		.filter_map(|(id, tile_def)| match tile_def {
+			Ok(tile_def) => Some(Ok((id, tile_def))),
+			Err(err @ TilesetError::CircularReference(..)) => Some(Err(err)),
+			Err(..) => None,
+		})
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.unzip();
 
-	let handles = load_tile_handles(tile_defs, loader);
+	let handles = loader.settings.load_tile_handles(tile_defs, loader);
 
-	Ok(tile_paths
-		.iter()
-		.map(|(id, ..)| *id)
-		.zip(handles.into_iter().map(|handle| handle))
-		.collect())
+	Ok(group_ids.into_iter().zip(handles).collect())
 }
 
-/// Load the tile definition at the given path and return its corresponding [TileDef]
+/// Lexically resolves any `.` and `..` components in the given path
+fn normalize_path(path: &Path) -> PathBuf {
+	let mut normalized = PathBuf::new();
+	for component in path.components() {
+		match component {
+			Component::CurDir => {},
+			Component::ParentDir => {
+				if !normalized.pop() {
+					normalized.push(component);
+				}
+			},
+			_ => normalized.push(component),
+		}
+	}
+	normalized
+}
+
+/// Load the tile definition for the given entry and return its corresponding [TileDef]
 ///
 /// The path is always relative to the tileset's configuration file path
-async fn load_tile(context: &LoadContext<'_>, path: &str) -> Result<TileDef, TilesetError> {
+async fn load_tile(
+	context: &LoadContext<'_>,
+	settings: &TilesetLoadSettings,
+	entry: &TilesetEntry,
+) -> Result<TileDef, TilesetError> {
+	let entry_path = settings.expand(entry.path());
 	let path = if let Some(parent) = context.path().parent() {
-		parent.join(path)
+		parent.join(&entry_path)
 	} else {
-		Path::new(path).to_path_buf()
+		Path::new(&entry_path).to_path_buf()
 	};
-	let bytes = context
-		.read_asset_bytes(&path)
-		.await
-		.map_err(|err| TilesetError::AssetIoError(err))?;
-	let def = ron::de::from_bytes::<TileDef>(&bytes)
-		.map_err(|err| TilesetError::InvalidDefinition(err))?;
-	Ok(def)
+
+	// Collect the chain of definitions, from the entry's definition up to its root base.
+	// The tileset's own file is visited first so that referring back to it is also caught.
+	let mut visited = vec![normalize_path(context.path())];
+	let mut chain = Vec::<PartialTileDef>::new();
+	let mut next = Some(path);
+	while let Some(path) = next.take() {
+		let path = normalize_path(&path);
+		if visited.contains(&path) {
+			visited.push(path);
+			return Err(TilesetError::CircularReference(visited));
+		}
+
+		let bytes = context
+			.read_asset_bytes(&path)
+			.await
+			.map_err(TilesetError::AssetIoError)?;
+		let def = settings.parse_partial_tile_def(&bytes)?;
+		next = def.base.as_ref().map(|base| {
+			let parent = path.parent().unwrap_or_else(|| Path::new(""));
+			parent.join(settings.expand(base))
+		});
+
+		visited.push(path);
+		chain.push(def);
+	}
+
+	let mut def = chain
+		.into_iter()
+		.rev()
+		.reduce(|base, def| def.inherit(base))
+		.unwrap_or_default();
+	if let Some(name) = entry.name() {
+		def.name = Some(name.to_string());
+	}
+
+	complete_tile_def(def)
+}
+
+/// Load the sheet image and slice it into its individual tiles
+///
+/// Returns the group ID, name, and texture of each tile
+async fn load_sheet(
+	context: &LoadContext<'_>,
+	sheet: &TilesetSheetDef,
+	sheet_path: &str,
+	supported_compressed_formats: CompressedImageFormats,
+) -> Result<Vec<(TileGroupId, String, Image)>, TilesetError> {
+	let count = sheet.cell_count()?;
+	let path = if let Some(parent) = context.path().parent() {
+		parent.join(sheet_path)
+	} else {
+		PathBuf::from(sheet_path)
+	};
+	let (.., image) = load_image(
+		context,
+		HandleId::random::<Image>(),
+		path,
+		supported_compressed_formats,
+	)
+	.await?;
+
+	let image_size = image.texture_descriptor.size;
+	let image_size = UVec2::new(image_size.width, image_size.height);
+	let tile_size = image_size / sheet.grid;
+
+	Ok(slice_sheet(&image, tile_size, 0..count as usize)?
+		.into_iter()
+		.enumerate()
+		.map(|(index, texture)| {
+			let name = sheet
+				.names
+				.get(&index)
+				.cloned()
+				.unwrap_or_else(|| format!("tile_{}", index));
+			// Cannot overflow since the cell count was checked above
+			(sheet.first_id + index as TileGroupId, name, texture)
+		})
+		.collect())
 }
 
 /// Load an image at the given path
+///
+/// The image is decoded on the `AsyncComputeTaskPool` so that multiple images can be decoded in parallel
 async fn load_image(
 	context: &LoadContext<'_>,
 	id: HandleId,
@@ -233,15 +752,95 @@ async fn load_image(
 	let bytes = context
 		.read_asset_bytes(path.clone())
 		.await
-		.map_err(|err| TilesetError::AssetIoError(err))?;
-	let path = path.as_path();
-	let ext = path.extension().unwrap().to_str().unwrap();
-	let img = Image::from_buffer(
-		&bytes,
-		ImageType::Extension(ext),
-		supported_compressed_formats,
-		true,
-	)
-	.map_err(|err| TilesetError::ImageError(err))?;
+		.map_err(TilesetError::AssetIoError)?;
+	let ext = path
+		.extension()
+		.and_then(|ext| ext.to_str())
+		.map(|ext| ext.to_lowercase())
+		.ok_or_else(|| TilesetError::MissingExtension(path.clone()))?;
+	let img = AsyncComputeTaskPool::get()
+		.spawn(async move {
+			Image::from_buffer(
+				&bytes,
+				ImageType::Extension(&ext),
+				supported_compressed_formats,
+				true,
+			)
+		})
+		.await
+		.map_err(TilesetError::ImageError)?;
 	Ok((id, img))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy_tileset_tiles::prelude::{StandardTileDef, TileDefType, TileFlip, TileHandleType};
+	use std::cell::RefCell;
+
+	/// A loader that records the path of every texture it loads
+	#[derive(Default)]
+	struct PathLoader(RefCell<Vec<PathBuf>>);
+
+	impl TextureLoader for PathLoader {
+		fn load_texture<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<Image> {
+			self.0.borrow_mut().push(path.into().path().to_path_buf());
+			Handle::weak(HandleId::random::<Image>())
+		}
+	}
+
+	#[test]
+	fn should_expand_vars() {
+		let settings = TilesetLoadSettings::default();
+		assert_eq!("${THEME}/wall.png", settings.expand("${THEME}/wall.png"));
+
+		settings.set_var("THEME", "winter");
+		settings.set_var("SIZE", "16");
+		assert_eq!(
+			"winter/16/wall_16.png",
+			settings.expand("${THEME}/${SIZE}/wall_${SIZE}.png")
+		);
+		assert_eq!("${OTHER}/wall.png", settings.expand("${OTHER}/wall.png"));
+		assert_eq!("winter/${SIZE", settings.expand("${THEME}/${SIZE"));
+
+		assert_eq!(Some(String::from("winter")), settings.remove_var("THEME"));
+		assert_eq!("${THEME}/wall.png", settings.expand("${THEME}/wall.png"));
+	}
+
+	#[test]
+	fn should_load_expanded_tile_handles() {
+		let settings = TilesetLoadSettings::default();
+		settings.set_var("THEME", "winter");
+		let tile = TileDef {
+			name: String::from("Wall"),
+			tile: TileDefType::Standard(StandardTileDef::Single(String::from(
+				"textures/${THEME}/wall.png",
+			))),
+			flip: TileFlip::default(),
+			sfx: None,
+		};
+
+		let loader = PathLoader::default();
+		let handles = settings.load_tile_handles(vec![tile], &loader);
+		assert!(matches!(handles[0].tile, TileHandleType::Standard(..)));
+		assert_eq!(
+			vec![PathBuf::from("textures/winter/wall.png")],
+			loader.0.into_inner()
+		);
+	}
+
+	#[test]
+	fn should_reject_invalid_sheet_grids() {
+		let sheet = |grid: UVec2, first_id: TileGroupId| TilesetSheetDef {
+			grid,
+			first_id,
+			..Default::default()
+		};
+
+		assert!(matches!(sheet(UVec2::new(4, 2), 10).cell_count(), Ok(8)));
+		assert!(sheet(UVec2::new(0, 2), 0).cell_count().is_err());
+		assert!(sheet(UVec2::new(u32::MAX, 2), 0).cell_count().is_err());
+		assert!(sheet(UVec2::new(4, 2), u32::MAX - 6).cell_count().is_err());
+		assert!(sheet(UVec2::new(4, 2), u32::MAX - 7).cell_count().is_ok());
+	}
+}
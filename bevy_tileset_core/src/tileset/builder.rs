@@ -1,9 +1,13 @@
 use crate::ids::PartialTileId;
 use crate::prelude::*;
-use bevy::prelude::{Handle, Image};
+use crate::tileset::load::{load_tile_handles, TextureLoader};
+use bevy::asset::{Asset, AssetPath, HandleId};
+use bevy::prelude::{Handle, Image, UVec2};
+use bevy::render::render_resource::{Extent3d, TextureDimension};
 use bevy_tile_atlas::{TextureStore, TileAtlasBuilder, TileAtlasBuilderError};
 use bevy_tileset_tiles::prelude::*;
 use std::collections::HashMap;
+use std::ops::Range;
 
 /// A builder for constructing a [`Tileset`]
 #[derive(Default)]
@@ -20,8 +24,16 @@ pub struct TilesetBuilder {
 	tile_handles: HashMap<usize, Handle<Image>>,
 	/// The tile IDs mapped by their index in the atlas
 	tile_indices: HashMap<usize, PartialTileId>,
+	/// The group ID of the tile used to represent an empty cell
+	empty_tile: Option<TileGroupId>,
+	/// Whether the tile handles should be stored as strong handles
+	strong_handles: bool,
+	/// The textures sliced from spritesheets mapped by their (weak) handle ID
+	sliced_textures: HashMap<HandleId, Image>,
 	/// The current tile group ID being processed
 	current_group: TileGroupId,
+	/// The name of the current tile being processed
+	current_name: String,
 	/// The current variant index being processed
 	#[cfg(feature = "variants")]
 	current_variant: Option<usize>,
@@ -38,10 +50,14 @@ impl TilesetBuilder {
 			atlas_builder,
 			tile_ids: Default::default(),
 			current_group: Default::default(),
+			current_name: Default::default(),
 			tile_indices: Default::default(),
 			tile_names: Default::default(),
 			tiles: Default::default(),
 			tile_handles: Default::default(),
+			empty_tile: None,
+			strong_handles: false,
+			sliced_textures: Default::default(),
 			#[cfg(feature = "variants")]
 			current_variant: None,
 			#[cfg(feature = "auto-tile")]
@@ -49,6 +65,70 @@ impl TilesetBuilder {
 		}
 	}
 
+	/// Create a builder from a set of tile definitions whose images are already in memory
+	///
+	/// Unlike the asset loader, this does not read anything from an `assets/` directory, which
+	/// makes it useful for builds that embed their assets in the binary (i.e. via `include_bytes!`).
+	///
+	/// Each image is mapped by the exact path used to reference it in the tile definitions.
+	///
+	/// # Arguments
+	///
+	/// * `defs`: The tile definitions mapped by their group ID
+	/// * `images`: The images used by the tile definitions mapped by their path
+	///
+	/// returns: Result<TilesetBuilder, TilesetError>
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use std::collections::HashMap;
+	/// # use bevy::prelude::*;
+	/// # use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn create_tileset(mut images: ResMut<Assets<Image>>) {
+	/// 	// These would usually be embedded with `include_bytes!` (and decoded with `Image::from_buffer`)
+	/// 	let def = parse_tile_def(br#"(name: "Grass", tile: Standard("textures/grass.png"))"#).unwrap();
+	/// 	let image = Image::new_fill(
+	/// 		Extent3d { width: 16, height: 16, depth_or_array_layers: 1 },
+	/// 		TextureDimension::D2,
+	/// 		&[0, 255, 0, 255],
+	/// 		TextureFormat::Rgba8UnormSrgb,
+	/// 	);
+	///
+	/// 	let builder = TilesetBuilder::from_defs(
+	/// 		vec![(0, def)],
+	/// 		HashMap::from([(String::from("textures/grass.png"), image)]),
+	/// 	).unwrap();
+	/// 	let raw_tileset = builder.build("My Tileset", 0, &mut images).unwrap();
+	/// }
+	/// ```
+	pub fn from_defs<TDefs: IntoIterator<Item = (TileGroupId, TileDef)>>(
+		defs: TDefs,
+		images: HashMap<String, Image>,
+	) -> Result<Self, TilesetError> {
+		let mut store = MemoryTextureStore {
+			images: images
+				.into_iter()
+				.map(|(path, image)| (HandleId::from(path.as_str()), image))
+				.collect(),
+		};
+
+		let (group_ids, defs): (Vec<_>, Vec<_>) = defs.into_iter().unzip();
+		let tile_handles = load_tile_handles(defs, &store);
+
+		let mut builder = Self::default();
+		for (group_id, tile_handle) in group_ids.into_iter().zip(tile_handles) {
+			builder.add_tile(tile_handle, group_id, &store)?;
+		}
+
+		// The builder needs to hold onto the images until the atlas is built
+		builder.sliced_textures.extend(store.images.drain());
+
+		Ok(builder)
+	}
+
 	/// Build the raw tileset
 	///
 	/// # Arguments
@@ -64,7 +144,12 @@ impl TilesetBuilder {
 		texture_store: &mut TStore,
 	) -> Result<RawTileset, TileAtlasBuilderError> {
 		let tile_size = self.atlas_builder.get_tile_size().unwrap_or_default();
-		let atlas = self.atlas_builder.finish(texture_store)?;
+		let empty_tile = self.empty_tile.map(|group_id| TileId::new(group_id, id));
+		let mut texture_store = SlicedTextureStore {
+			store: texture_store,
+			sliced: &self.sliced_textures,
+		};
+		let atlas = self.atlas_builder.finish(&mut texture_store)?;
 		let size = atlas.size;
 		Ok(RawTileset {
 			name: name.into(),
@@ -78,12 +163,106 @@ impl TilesetBuilder {
 				.collect(),
 			tile_names: self.tile_names,
 			tile_handles: self.tile_handles,
+			empty_tile,
 			tile_size,
 			atlas,
 			size,
 		})
 	}
 
+	/// Add all of the given tiles and build the raw tileset in one go
+	///
+	/// This is a shorthand for calling [`add_tile`](Self::add_tile) for each tile followed by
+	/// [`build`](Self::build), stopping at the first tile that fails to be added.
+	///
+	/// # Arguments
+	///
+	/// * `tiles`: The tiles to add mapped by their group ID
+	/// * `name`: The name of the tileset
+	/// * `id`: The ID of the tileset
+	/// * `texture_store`: The store of textures
+	///
+	/// returns: Result<RawTileset, TilesetError>
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	/// # use bevy_tileset_tiles::prelude::*;
+	///
+	/// fn tileset_creator(mut textures: ResMut<Assets<Image>>) {
+	/// 	let tiles = vec![
+	/// 		(0, TileHandle::new_standard("Grass", Handle::default())),
+	/// 		(1, TileHandle::new_standard("Dirt", Handle::default())),
+	/// 	];
+	/// 	let raw_tileset = TilesetBuilder::default()
+	/// 		.build_from(tiles, "My Tileset", 123, &mut textures)
+	/// 		.unwrap();
+	/// }
+	/// ```
+	pub fn build_from<TTiles, TName, TStore>(
+		mut self,
+		tiles: TTiles,
+		name: TName,
+		id: TilesetId,
+		texture_store: &mut TStore,
+	) -> Result<RawTileset, TilesetError>
+	where
+		TTiles: IntoIterator<Item = (TileGroupId, TileHandle)>,
+		TName: Into<String>,
+		TStore: TextureStore,
+	{
+		for (group_id, tile_handle) in tiles {
+			self.add_tile(tile_handle, group_id, texture_store)?;
+		}
+
+		self.build(name, id, texture_store)
+			.map_err(TilesetError::AtlasError)
+	}
+
+	/// Sets whether the built tileset should keep strong handles to its source textures
+	///
+	/// By default, the tileset only stores weak handles, meaning the source images may be unloaded
+	/// once nothing else holds onto them. Enabling this makes the tileset retain the handles it was
+	/// given, keeping every source image resident in memory for as long as the tileset exists.
+	///
+	/// Note that weak handles given to the builder will remain weak.
+	///
+	/// # Arguments
+	///
+	/// * `strong_handles`: Whether to store strong handles
+	///
+	/// returns: TilesetBuilder
+	///
+	pub fn with_strong_handles(mut self, strong_handles: bool) -> Self {
+		self.strong_handles = strong_handles;
+		self
+	}
+
+	/// Marks the tile with the given group ID as the "empty" tile
+	///
+	/// This tile is used to represent an empty (or "air") cell, which allows tiles to be erased
+	/// without relying on a tile with some hardcoded name.
+	///
+	/// The tile must already have been added to this builder.
+	///
+	/// # Arguments
+	///
+	/// * `group_id`: The group ID of the empty tile (or `None` to unset it)
+	///
+	/// returns: Result<(), TilesetError>
+	///
+	pub fn set_empty_tile(&mut self, group_id: Option<TileGroupId>) -> Result<(), TilesetError> {
+		if let Some(group_id) = group_id {
+			if !self.tiles.contains_key(&group_id) {
+				return Err(TilesetError::UnknownEmptyTile(group_id));
+			}
+		}
+		self.empty_tile = group_id;
+		Ok(())
+	}
+
 	/// Add a tile to the tileset being built
 	///
 	/// # Arguments
@@ -121,11 +300,79 @@ impl TilesetBuilder {
 		let name = tile_handle.name.clone();
 
 		self.current_group = group_id;
+		self.current_name = name.clone();
+
+		let tile_type = self.get_tile_type(tile_handle.tile, texture_store)?;
+		Self::check_not_empty(&name, &tile_type)?;
 
-		let tile = TileData::new(
-			tile_handle.name,
-			self.get_tile_type(tile_handle.tile, texture_store)?,
-		);
+		let tile = TileData::new(tile_handle.name, tile_type)
+			.with_flip(tile_handle.flip)
+			.with_sfx(tile_handle.sfx);
+
+		self.tile_ids.insert(name.clone(), group_id);
+		self.tile_names.insert(group_id, name);
+		Ok(self.tiles.insert(group_id, tile))
+	}
+
+	/// Add a tile to the tileset being built, returning the atlas indices assigned to its textures
+	///
+	/// This works just like [`add_tile`](Self::add_tile), but also returns every atlas index
+	/// used by the added tile (sorted in ascending order).
+	///
+	/// # Arguments
+	///
+	/// * `tile_handle`: The tile to add
+	/// * `group_id`: The group ID of the tile (this should be unique across tiles)
+	/// * `texture_store`: The store of textures
+	///
+	/// returns: Result<(Option<TileData>, Vec<usize>), TilesetError>
+	///
+	pub fn add_tile_with_indices<TStore: TextureStore>(
+		&mut self,
+		tile_handle: TileHandle,
+		group_id: TileGroupId,
+		texture_store: &TStore,
+	) -> Result<(Option<TileData>, Vec<usize>), TilesetError> {
+		let data = self.add_tile(tile_handle, group_id, texture_store)?;
+		let mut indices = self
+			.tile_indices
+			.iter()
+			.filter(|(.., id)| id.group_id == group_id)
+			.map(|(index, ..)| *index)
+			.collect::<Vec<_>>();
+		indices.sort_unstable();
+		Ok((data, indices))
+	}
+
+	/// Add a standard tile using a texture that is not stored as an asset
+	///
+	/// This is useful for textures generated at runtime, such as those sliced from a larger image.
+	///
+	/// # Arguments
+	///
+	/// * `name`: The name of the tile
+	/// * `group_id`: The group ID of the tile (this should be unique across tiles)
+	/// * `texture`: The tile's texture
+	///
+	/// returns: Result<Option<TileData>, TilesetError>
+	///
+	pub fn add_standard_image<TName: Into<String>>(
+		&mut self,
+		name: TName,
+		group_id: TileGroupId,
+		texture: Image,
+	) -> Result<Option<TileData>, TilesetError> {
+		if self.tiles.contains_key(&group_id) {
+			return Err(TilesetError::TileAlreadyExists(group_id));
+		}
+
+		let name = name.into();
+
+		self.current_group = group_id;
+		self.current_name = name.clone();
+
+		let index = self.add_sliced_texture(texture)?;
+		let tile = TileData::new(name.clone(), TileType::Standard(index));
 
 		self.tile_ids.insert(name.clone(), group_id);
 		self.tile_names.insert(group_id, name);
@@ -140,19 +387,45 @@ impl TilesetBuilder {
 		Ok(match tile {
 			TileHandleType::Standard(handle) => {
 				TileType::Standard(self.insert_handle(&handle, texture_store)?)
-			}
+			},
 			TileHandleType::Animated(anim) => {
 				TileType::Animated(self.create_animated(anim, texture_store)?)
-			}
+			},
 			#[cfg(feature = "variants")]
 			TileHandleType::Variant(variants) => {
 				TileType::Variant(self.create_variants(variants, texture_store)?)
-			}
+			},
 			#[cfg(feature = "auto-tile")]
 			TileHandleType::Auto(autos) => TileType::Auto(self.create_autos(autos, texture_store)?),
 		})
 	}
 
+	/// Ensures that the given tile type has something to select
+	///
+	/// Variants that fail to load are skipped, so without this check a Variant or Auto tile could
+	/// silently end up with nothing to display.
+	fn check_not_empty(name: &str, tile_type: &TileType) -> Result<(), TilesetError> {
+		let empty: Option<&str> = match tile_type {
+			#[cfg(feature = "variants")]
+			TileType::Variant(variants) if variants.is_empty() => Some("variant"),
+			#[cfg(feature = "auto-tile")]
+			TileType::Auto(autos) if autos.is_empty() => Some("auto tile"),
+			#[cfg(feature = "auto-tile")]
+			TileType::Auto(autos) if autos.iter().any(|auto| auto.variants().is_empty()) => {
+				Some("variant per auto tile")
+			},
+			_ => None,
+		};
+
+		match empty {
+			Some(kind) => Err(TilesetError::InvalidData {
+				expected: format!("At least one {} in tile {:?}", kind, name),
+				found: String::from("None"),
+			}),
+			None => Ok(()),
+		}
+	}
+
 	#[cfg(feature = "auto-tile")]
 	fn create_autos<TStore: TextureStore>(
 		&mut self,
@@ -166,14 +439,15 @@ impl TilesetBuilder {
 				let auto = AutoTileData::new(
 					auto.rule,
 					self.create_variants(auto.variants, texture_store)?,
-				);
+				)
+				.with_fallback(auto.fallback)
+				.with_fallback_tile(auto.fallback_tile);
 				self.current_auto = Some(1 + self.current_auto.unwrap_or(0));
 				Ok(auto)
 			})
-			.flat_map(|x| x.ok())
-			.collect();
+			.collect::<Result<Vec<_>, _>>();
 		self.current_auto = None;
-		Ok(autos)
+		autos
 	}
 
 	#[cfg(feature = "variants")]
@@ -191,19 +465,18 @@ impl TilesetBuilder {
 					match variant.tile {
 						SimpleTileHandle::Standard(handle) => {
 							SimpleTileType::Standard(self.insert_handle(&handle, texture_store)?)
-						}
+						},
 						SimpleTileHandle::Animated(anim) => {
 							SimpleTileType::Animated(self.create_animated(anim, texture_store)?)
-						}
+						},
 					},
 				);
 				self.current_variant = Some(1 + self.current_variant.unwrap_or(0));
 				Ok(variant)
 			})
-			.filter_map(|x| x.ok())
-			.collect();
+			.collect::<Result<Vec<_>, _>>();
 		self.current_variant = None;
-		Ok(variants)
+		variants
 	}
 
 	fn create_animated<TStore: TextureStore>(
@@ -211,28 +484,70 @@ impl TilesetBuilder {
 		anim: AnimatedTileHandle,
 		texture_store: &TStore,
 	) -> Result<AnimatedTileData, TilesetError> {
-		let (mut start, mut end) = (-1, -1);
+		let mut indices = Vec::with_capacity(anim.frames.len());
 		for frame in &anim.frames {
-			let index = self.insert_handle(frame, texture_store)?;
-			if start == -1 {
-				start = index as i32;
-			} else {
-				end = index as i32;
+			indices.push(self.insert_handle(frame, texture_store)?);
+		}
+		if let Some(ref sheet) = anim.sheet {
+			let texture = texture_store
+				.get(&sheet.handle)
+				.ok_or(TilesetError::ImageNotFound)?;
+			for frame in slice_sheet(texture, sheet.frame_size, 0..sheet.frames)? {
+				indices.push(self.add_sliced_texture(frame)?);
 			}
 		}
-
-		if start < 0 || end < 0 {
-			return Err(TilesetError::InvalidData {
-				expected: String::from("At least one animation frame"),
-				found: String::from("Zero animation frames"),
-			});
+		if let Some(range) = anim.indices {
+			if !indices.is_empty() {
+				return Err(TilesetError::InvalidData {
+					expected: String::from("Either animation frames or atlas indices"),
+					found: String::from("Both"),
+				});
+			}
+			if let Some(index) = range
+				.clone()
+				.find(|index| !self.tile_handles.contains_key(index))
+			{
+				return Err(TilesetError::InvalidData {
+					expected: format!("A texture at atlas index {}", index),
+					found: String::from("None"),
+				});
+			}
+			indices.extend(range);
 		}
 
-		Ok(AnimatedTileData::new(
-			anim.speed,
-			start as usize,
-			end as usize,
-		))
+		// A single frame is both the start and the end of the animation
+		let (start, end) = match (indices.first(), indices.last()) {
+			(Some(start), Some(end)) => (*start, *end),
+			_ => {
+				return Err(TilesetError::InvalidData {
+					expected: String::from("At least one animation frame"),
+					found: String::from("Zero animation frames"),
+				})
+			},
+		};
+
+		Ok(AnimatedTileData::new(anim.speed, start, end))
+	}
+
+	/// Gives the builder textures that do not exist as assets, mapped by the handle used to reference them
+	///
+	/// Tiles added afterwards may then use these handles just like any other handle. The textures
+	/// are held by the builder until the atlas is built.
+	pub(crate) fn insert_sliced_textures<TTextures: IntoIterator<Item = (HandleId, Image)>>(
+		&mut self,
+		textures: TTextures,
+	) {
+		self.sliced_textures.extend(textures);
+	}
+
+	/// Adds a texture that does not exist as an asset
+	///
+	/// These textures are held by the builder until the atlas is built.
+	fn add_sliced_texture(&mut self, texture: Image) -> Result<usize, TilesetError> {
+		let handle = Handle::weak(HandleId::random::<Image>());
+		let index = self.add_texture(&handle, &texture)?;
+		self.sliced_textures.insert(handle.id(), texture);
+		Ok(index)
 	}
 
 	fn insert_handle<TStore: TextureStore>(
@@ -241,10 +556,17 @@ impl TilesetBuilder {
 		textures: &TStore,
 	) -> Result<usize, TilesetError> {
 		if let Some(texture) = textures.get(handle) {
-			self.add_texture(handle, texture)
-		} else {
-			Err(TilesetError::ImageNotFound)
+			return self.add_texture(handle, texture);
 		}
+
+		// The texture may have been given to the builder directly
+		let texture = self
+			.sliced_textures
+			.remove(&handle.id())
+			.ok_or(TilesetError::ImageNotFound)?;
+		let index = self.add_texture(handle, &texture);
+		self.sliced_textures.insert(handle.id(), texture);
+		index
 	}
 
 	pub fn add_texture(
@@ -255,7 +577,11 @@ impl TilesetBuilder {
 		let index = self
 			.atlas_builder
 			.add_texture(handle.clone_weak(), texture)
-			.map_err(|err| TilesetError::AtlasError(err))?;
+			.map_err(|error| TilesetError::TileAtlasError {
+				name: self.current_name.clone(),
+				handle: handle.id(),
+				error,
+			})?;
 
 		let id = PartialTileId {
 			group_id: self.current_group,
@@ -265,8 +591,253 @@ impl TilesetBuilder {
 			auto_index: self.current_auto,
 		};
 		self.tile_indices.insert(index, id);
-		self.tile_handles.insert(index, handle.clone_weak());
+		let handle = if self.strong_handles {
+			handle.clone()
+		} else {
+			handle.clone_weak()
+		};
+		self.tile_handles.insert(index, handle);
 
 		Ok(index)
 	}
 }
+
+/// A [`TextureStore`] that also contains the textures sliced from animation spritesheets
+struct SlicedTextureStore<'a, TStore: TextureStore> {
+	store: &'a mut TStore,
+	sliced: &'a HashMap<HandleId, Image>,
+}
+
+impl<'a, TStore: TextureStore> TextureStore for SlicedTextureStore<'a, TStore> {
+	fn add(&mut self, asset: Image) -> Handle<Image> {
+		self.store.add(asset)
+	}
+
+	fn get<H: Into<HandleId>>(&self, handle: H) -> Option<&Image> {
+		let id = handle.into();
+		self.sliced.get(&id).or_else(|| self.store.get(id))
+	}
+}
+
+/// A store (and loader) of images that were given directly to the builder, mapped by their path
+#[derive(Default)]
+pub(crate) struct MemoryTextureStore {
+	pub(crate) images: HashMap<HandleId, Image>,
+}
+
+impl TextureLoader for MemoryTextureStore {
+	fn load_texture<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<Image> {
+		Handle::weak(HandleId::from(path.into()))
+	}
+}
+
+impl TextureStore for MemoryTextureStore {
+	fn add(&mut self, asset: Image) -> Handle<Image> {
+		let handle = Handle::weak(HandleId::random::<Image>());
+		self.images.insert(handle.id(), asset);
+		handle
+	}
+
+	fn get<H: Into<HandleId>>(&self, handle: H) -> Option<&Image> {
+		self.images.get(&handle.into())
+	}
+}
+
+/// Slices the given spritesheet into its individual frames
+///
+/// Frames are read left-to-right, top-to-bottom, starting at the top-left corner.
+///
+/// # Arguments
+///
+/// * `sheet`: The spritesheet texture
+/// * `frame_size`: The size of a single frame (in pixels)
+/// * `frames`: The cells to slice (counted left-to-right, top-to-bottom)
+///
+/// returns: Result<Vec<Image>, TilesetError>
+///
+pub(crate) fn slice_sheet(
+	sheet: &Image,
+	frame_size: UVec2,
+	frames: Range<usize>,
+) -> Result<Vec<Image>, TilesetError> {
+	let sheet_width = sheet.texture_descriptor.size.width as usize;
+	let sheet_height = sheet.texture_descriptor.size.height as usize;
+	let (frame_width, frame_height) = (frame_size.x as usize, frame_size.y as usize);
+
+	let columns = sheet_width.checked_div(frame_width).unwrap_or_default();
+	let rows = sheet_height.checked_div(frame_height).unwrap_or_default();
+	if frames.end > columns * rows {
+		return Err(TilesetError::InvalidData {
+			expected: format!(
+				"A spritesheet containing {} frames of size {}",
+				frames.end, frame_size
+			),
+			found: format!(
+				"A spritesheet of size {}x{} ({} frames)",
+				sheet_width,
+				sheet_height,
+				columns * rows
+			),
+		});
+	}
+
+	frames
+		.map(|frame| {
+			let min = UVec2::new((frame % columns) as u32, (frame / columns) as u32) * frame_size;
+			crop_texture(sheet, min, frame_size)
+		})
+		.collect()
+}
+
+/// Copies the given area out of a texture
+///
+/// The area is expected to lie entirely within the texture. Only uncompressed formats can be
+/// cropped.
+///
+/// # Arguments
+///
+/// * `texture`: The texture to copy from
+/// * `min`: The top-left corner of the area (in pixels)
+/// * `size`: The size of the area (in pixels)
+///
+/// returns: Result<Image, TilesetError>
+///
+pub(crate) fn crop_texture(
+	texture: &Image,
+	min: UVec2,
+	size: UVec2,
+) -> Result<Image, TilesetError> {
+	let format = texture.texture_descriptor.format;
+	let pixel_size = match (format.block_dimensions(), format.block_size(None)) {
+		((1, 1), Some(pixel_size)) => pixel_size as usize,
+		_ => return Err(TilesetError::UnsupportedFormat(format)),
+	};
+	let texture_stride = texture.texture_descriptor.size.width as usize * pixel_size;
+	let stride = size.x as usize * pixel_size;
+	let x = min.x as usize * pixel_size;
+	let y = min.y as usize;
+
+	let data = (y..y + size.y as usize)
+		.flat_map(|row| {
+			let start = row * texture_stride + x;
+			texture.data[start..start + stride].iter().copied()
+		})
+		.collect();
+
+	Ok(Image::new(
+		Extent3d {
+			width: size.x,
+			height: size.y,
+			depth_or_array_layers: 1,
+		},
+		TextureDimension::D2,
+		data,
+		format,
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tileset::fixtures::image;
+	use bevy::render::render_resource::TextureFormat;
+
+	fn animated(indices: Range<usize>) -> TileHandle {
+		TileHandle::new_animated(
+			"Animated",
+			AnimatedTileHandle {
+				speed: 1.0,
+				frames: Vec::new(),
+				sheet: None,
+				indices: Some(indices),
+			},
+		)
+	}
+
+	#[test]
+	fn should_animate_existing_indices() {
+		let mut store = MemoryTextureStore::default();
+		let mut builder = TilesetBuilder::default();
+		for (group_id, pixel) in [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]]
+			.into_iter()
+			.enumerate()
+		{
+			let name = format!("tile_{}", group_id);
+			builder
+				.add_standard_image(name, group_id as TileGroupId, image(pixel))
+				.unwrap();
+		}
+		builder.add_tile(animated(1..3), 3, &store).unwrap();
+
+		// Out of range
+		assert!(matches!(
+			builder.add_tile(animated(2..4), 4, &store),
+			Err(TilesetError::InvalidData { .. })
+		));
+
+		let tileset = builder.build("Sheet", 0, &mut store).unwrap();
+		assert_eq!(3, tileset.atlas().len());
+		assert!(matches!(
+			tileset.get_tile_index("Animated"),
+			Some(TileIndex::Animated {
+				start: 1,
+				end: 2,
+				..
+			})
+		));
+	}
+
+	#[test]
+	fn should_animate_single_index() {
+		let mut store = MemoryTextureStore::default();
+		let mut builder = TilesetBuilder::default();
+		for group_id in 0..2 {
+			let name = format!("tile_{}", group_id);
+			builder
+				.add_standard_image(name, group_id, image([255, 0, 0, 255]))
+				.unwrap();
+		}
+		builder.add_tile(animated(1..2), 2, &store).unwrap();
+
+		let tileset = builder.build("Sheet", 0, &mut store).unwrap();
+		let index = tileset.get_tile_index("Animated").unwrap();
+		assert!(matches!(
+			index,
+			TileIndex::Animated {
+				start: 1,
+				end: 1,
+				..
+			}
+		));
+		assert_eq!(1, index.frame_count());
+	}
+
+	#[test]
+	fn should_reject_compressed_textures() {
+		let mut texture = image([255, 0, 0, 255]);
+		texture.texture_descriptor.format = TextureFormat::Bc1RgbaUnorm;
+
+		assert!(matches!(
+			crop_texture(&texture, UVec2::ZERO, UVec2::ONE),
+			Err(TilesetError::UnsupportedFormat(TextureFormat::Bc1RgbaUnorm))
+		));
+	}
+
+	#[test]
+	fn should_reject_unknown_empty_tile() {
+		let mut store = MemoryTextureStore::default();
+		let mut builder = TilesetBuilder::default();
+		assert!(matches!(
+			builder.set_empty_tile(Some(0)),
+			Err(TilesetError::UnknownEmptyTile(0))
+		));
+
+		builder
+			.add_standard_image("Empty", 0, image([0, 0, 0, 0]))
+			.unwrap();
+		builder.set_empty_tile(Some(0)).unwrap();
+
+		let tileset = builder.build("Empty", 3, &mut store).unwrap();
+		assert_eq!(Some(&TileId::new(0, 3)), tileset.empty_tile());
+	}
+}
@@ -0,0 +1,295 @@
+//! An in-memory tileset model for editing tiles at runtime
+
+use bevy::asset::LoadState;
+use bevy::prelude::{AssetServer, Assets, Image};
+use bevy_tile_atlas::TextureStore;
+use bevy_tileset_tiles::prelude::{TileDef, TileHandle};
+
+use crate::prelude::{
+	load_tile_handles, RawTileset, TileGroupId, TilesetBuilder, TilesetError, TilesetId,
+};
+
+/// A tileset made up of plain [`TileDef`]s that can be freely edited and then baked into a [`RawTileset`]
+///
+/// This allows tools (such as an in-game editor) to add, remove, reorder, or change tiles without
+/// having to write and re-parse any definition files.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tileset_core::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Editor {
+/// 	tileset: EditableTileset,
+/// 	handle: Handle<Tileset>,
+/// 	is_dirty: bool,
+/// }
+///
+/// fn rebake(
+/// 	mut editor: ResMut<Editor>,
+/// 	asset_server: Res<AssetServer>,
+/// 	mut textures: ResMut<Assets<Image>>,
+/// 	mut atlases: ResMut<Assets<TextureAtlas>>,
+/// 	mut tilesets: ResMut<Assets<Tileset>>,
+/// ) {
+/// 	if !editor.is_dirty {
+/// 		return;
+/// 	}
+///
+/// 	if let Ok(Some(raw_tileset)) = editor.tileset.bake(&asset_server, &mut textures) {
+/// 		let _ = tilesets.set(editor.handle.clone(), raw_tileset.into_asset(&mut atlases));
+/// 		editor.is_dirty = false;
+/// 	}
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EditableTileset {
+	/// The name of the tileset
+	pub name: String,
+	/// The ID of the tileset
+	pub id: TilesetId,
+	/// The tiles in this tileset (in their intended order) paired with their group ID
+	pub tiles: Vec<(TileGroupId, TileDef)>,
+	/// The group ID of the tile used to represent an empty cell (if any)
+	///
+	/// Baking fails if this is not the group ID of one of the tiles.
+	pub empty: Option<TileGroupId>,
+	/// The handles from the last call to [`bake`](Self::bake), which keep their images loaded
+	handles: Vec<TileHandle>,
+}
+
+impl EditableTileset {
+	/// Create a new, empty tileset
+	///
+	/// # Arguments
+	///
+	/// * `name`: The name of the tileset
+	/// * `id`: The ID of the tileset
+	///
+	/// returns: EditableTileset
+	///
+	pub fn new<TName: Into<String>>(name: TName, id: TilesetId) -> Self {
+		Self {
+			name: name.into(),
+			id,
+			tiles: Vec::new(),
+			empty: None,
+			handles: Vec::new(),
+		}
+	}
+
+	/// Add a tile to the end of this tileset
+	///
+	/// # Arguments
+	///
+	/// * `group_id`: The group ID of the tile
+	/// * `tile`: The tile's definition
+	///
+	/// returns: Result<(), TilesetError>
+	///
+	/// Returns an error if a tile with the given group ID already exists.
+	pub fn add_tile(&mut self, group_id: TileGroupId, tile: TileDef) -> Result<(), TilesetError> {
+		if self.get_tile(group_id).is_some() {
+			return Err(TilesetError::TileAlreadyExists(group_id));
+		}
+		self.tiles.push((group_id, tile));
+		Ok(())
+	}
+
+	/// Remove the tile with the given group ID
+	///
+	/// # Arguments
+	///
+	/// * `group_id`: The group ID of the tile
+	///
+	/// returns: Option<TileDef>
+	pub fn remove_tile(&mut self, group_id: TileGroupId) -> Option<TileDef> {
+		let index = self.tiles.iter().position(|(id, ..)| *id == group_id)?;
+		Some(self.tiles.remove(index).1)
+	}
+
+	/// Get the definition of the tile with the given group ID
+	///
+	/// # Arguments
+	///
+	/// * `group_id`: The group ID of the tile
+	///
+	/// returns: Option<&TileDef>
+	pub fn get_tile(&self, group_id: TileGroupId) -> Option<&TileDef> {
+		self.tiles
+			.iter()
+			.find_map(|(id, tile)| (*id == group_id).then_some(tile))
+	}
+
+	/// Get a mutable reference to the definition of the tile with the given group ID
+	///
+	/// # Arguments
+	///
+	/// * `group_id`: The group ID of the tile
+	///
+	/// returns: Option<&mut TileDef>
+	pub fn get_tile_mut(&mut self, group_id: TileGroupId) -> Option<&mut TileDef> {
+		self.tiles
+			.iter_mut()
+			.find_map(|(id, tile)| (*id == group_id).then_some(tile))
+	}
+
+	/// Build a [`RawTileset`] from the current tile definitions
+	///
+	/// Since images are loaded asynchronously, this returns `Ok(None)` until every image used by
+	/// the tiles has been loaded. Keep calling this (i.e. once per frame) until the tileset is
+	/// returned. The images are kept loaded by this tileset, so repeated calls are cheap.
+	///
+	/// # Arguments
+	///
+	/// * `asset_server`: The asset server used to load the tile images
+	/// * `textures`: The world's `Image` assets
+	///
+	/// returns: Result<Option<RawTileset>, TilesetError>
+	///
+	pub fn bake(
+		&mut self,
+		asset_server: &AssetServer,
+		textures: &mut Assets<Image>,
+	) -> Result<Option<RawTileset>, TilesetError> {
+		self.handles = load_tile_handles(
+			self.tiles.iter().map(|(.., tile)| tile.clone()),
+			asset_server,
+		);
+		let mut is_loaded = true;
+		for ((group_id, ..), handle) in self.tiles.iter().zip(self.handles.iter()) {
+			match handle.get_load_state(asset_server) {
+				LoadState::Loaded => {},
+				LoadState::Failed => return Err(TilesetError::ImageLoadFailed(*group_id)),
+				_ => is_loaded = false,
+			}
+		}
+		if !is_loaded {
+			return Ok(None);
+		}
+
+		self.build(textures).map(Some)
+	}
+
+	/// Build a [`RawTileset`] from the current tile definitions and the handles of the last bake
+	fn build<TStore: TextureStore>(
+		&self,
+		texture_store: &mut TStore,
+	) -> Result<RawTileset, TilesetError> {
+		let mut builder = TilesetBuilder::default();
+		let tiles = self
+			.tiles
+			.iter()
+			.map(|(group_id, ..)| *group_id)
+			.zip(self.handles.iter().cloned());
+		for (group_id, tile_handle) in tiles {
+			builder.add_tile(tile_handle, group_id, texture_store)?;
+		}
+		builder.set_empty_tile(self.empty)?;
+
+		builder
+			.build(self.name.clone(), self.id, texture_store)
+			.map_err(TilesetError::AtlasError)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tileset::fixtures::{image, MemoryTextureStore};
+	use bevy::asset::HandleId;
+	use bevy_tileset_tiles::prelude::{TileDefType, TileFlip};
+
+	fn tile(name: &str) -> TileDef {
+		TileDef {
+			name: name.to_string(),
+			tile: TileDefType::Standard(format!("{}.png", name).into()),
+			flip: TileFlip::default(),
+			sfx: None,
+		}
+	}
+
+	#[test]
+	fn should_add_and_remove_tiles() {
+		let mut tileset = EditableTileset::new("Editable", 0);
+		tileset.add_tile(3, tile("Grass")).unwrap();
+		tileset.add_tile(1, tile("Dirt")).unwrap();
+
+		let names = tileset
+			.tiles
+			.iter()
+			.map(|(group_id, tile)| (*group_id, tile.name.as_str()))
+			.collect::<Vec<_>>();
+		assert_eq!(vec![(3, "Grass"), (1, "Dirt")], names);
+
+		let removed = tileset.remove_tile(3).unwrap();
+		assert_eq!("Grass", removed.name);
+		assert!(tileset.get_tile(3).is_none());
+		assert!(tileset.remove_tile(3).is_none());
+		assert_eq!("Dirt", tileset.get_tile(1).unwrap().name);
+	}
+
+	#[test]
+	fn should_reject_duplicate_group_ids() {
+		let mut tileset = EditableTileset::new("Editable", 0);
+		tileset.add_tile(1, tile("Grass")).unwrap();
+
+		let result = tileset.add_tile(1, tile("Dirt"));
+		assert!(matches!(result, Err(TilesetError::TileAlreadyExists(1))));
+		assert_eq!(1, tileset.tiles.len());
+		assert_eq!("Grass", tileset.get_tile(1).unwrap().name);
+	}
+
+	#[test]
+	fn should_bake_edited_tiles() {
+		let mut store = MemoryTextureStore::default();
+		for (name, pixel) in [
+			("Grass", [0, 255, 0, 255]),
+			("Dirt", [128, 64, 0, 255]),
+			("Water", [0, 0, 255, 255]),
+			("Stone", [128, 128, 128, 255]),
+		] {
+			let id = HandleId::from(format!("{}.png", name).as_str());
+			store.images.insert(id, image(pixel));
+		}
+
+		// Mimics `bake` without needing an `AssetServer` to load the images
+		let mut bake = |tileset: &mut EditableTileset| {
+			let tiles = tileset.tiles.iter().map(|(.., tile)| tile.clone());
+			tileset.handles = load_tile_handles(tiles, &store);
+			tileset.build(&mut store).unwrap()
+		};
+
+		let mut tileset = EditableTileset::new("Editable", 7);
+		tileset.add_tile(0, tile("Grass")).unwrap();
+		tileset.add_tile(1, tile("Dirt")).unwrap();
+		tileset.add_tile(2, tile("Water")).unwrap();
+		tileset.remove_tile(1);
+		tileset.get_tile_mut(2).unwrap().name = String::from("Ocean");
+		tileset.add_tile(5, tile("Stone")).unwrap();
+
+		let raw_tileset = bake(&mut tileset);
+		assert_eq!("Editable", raw_tileset.name());
+		assert_eq!(7, *raw_tileset.id());
+		assert_eq!(3, raw_tileset.tile_count());
+		assert_eq!(3, raw_tileset.atlas().len());
+		for (index, (group_id, name)) in [(0, "Grass"), (2, "Ocean"), (5, "Stone")]
+			.into_iter()
+			.enumerate()
+		{
+			assert_eq!(Some(&group_id), raw_tileset.get_tile_group_id(name));
+			assert_eq!(Some(index), raw_tileset.get_base_tile_index(name));
+		}
+		assert!(raw_tileset.get_tile_group_id("Dirt").is_none());
+		assert!(raw_tileset.get_tile_group_id("Water").is_none());
+
+		// Reordering the tiles reorders the atlas
+		tileset.tiles.rotate_right(1);
+		let raw_tileset = bake(&mut tileset);
+		for (index, name) in ["Stone", "Grass", "Ocean"].into_iter().enumerate() {
+			assert_eq!(Some(index), raw_tileset.get_base_tile_index(name));
+		}
+	}
+}
@@ -1,23 +1,99 @@
 use crate::prelude::TileGroupId;
-use bevy::asset::AssetIoError;
+use bevy::asset::{AssetIoError, HandleId};
+use bevy::math::Vec2;
+use bevy::render::render_resource::TextureFormat;
 use bevy::render::texture::TextureError;
 use bevy_tile_atlas::TileAtlasBuilderError;
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum TilesetError {
 	#[error("image could not be found")]
 	ImageNotFound,
+	#[error("texture atlas could not be found")]
+	AtlasNotFound,
+	#[error("images of the tile with group ID {0:?} failed to load")]
+	ImageLoadFailed(TileGroupId),
 	#[error("could not load asset: {0:?}")]
 	AssetIoError(AssetIoError),
+	#[error("image path has no (valid) extension: {0:?}")]
+	MissingExtension(PathBuf),
 	#[error("could not read image: {0:?}")]
 	ImageError(TextureError),
+	#[error("could not encode image: {0:?}")]
+	ImageEncodeError(anyhow::Error),
+	#[error("image format {0:?} is not supported (only uncompressed formats can be sliced)")]
+	UnsupportedFormat(TextureFormat),
 	#[error("could not add tile to atlas: {0:?}")]
 	AtlasError(TileAtlasBuilderError),
+	#[error("could not add tile {name:?} (handle {handle:?}) to atlas: {error:?}")]
+	TileAtlasError {
+		name: String,
+		handle: HandleId,
+		error: TileAtlasBuilderError,
+	},
 	#[error("invalid tile data (expected {expected:?}, found {found:?})")]
 	InvalidData { expected: String, found: String },
 	#[error("could not read tile definition file: {0:?}")]
 	InvalidDefinition(ron::error::SpannedError),
 	#[error("tile with group ID {0:?} already exists in the tileset")]
 	TileAlreadyExists(TileGroupId),
+	#[error("empty tile with group ID {0:?} has not been added to the tileset")]
+	UnknownEmptyTile(TileGroupId),
+	#[error("definition inherits from {0:?}, which can only be resolved by the asset loader")]
+	UnresolvedBase(String),
+	#[error("definition file refers back to itself: {0:?}")]
+	CircularReference(Vec<PathBuf>),
+	#[error("replacement atlas has {found} textures, but the tileset expects {expected}")]
+	AtlasMismatch { expected: usize, found: usize },
+	#[error("replacement atlas texture {index} has size {found:?}, but the tileset expects {expected:?}")]
+	AtlasTileSize {
+		index: usize,
+		expected: Vec2,
+		found: Vec2,
+	},
+}
+
+/// The reason a tile could not be selected by its ID
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum SelectError {
+	#[error("no tile with group ID {0:?} exists in the tileset")]
+	UnknownGroup(TileGroupId),
+	#[error("variant index {index} is out of range (tile has {count} variants)")]
+	VariantOutOfRange { index: usize, count: usize },
+	#[error("auto tile index {index} is out of range (tile has {count} auto tiles)")]
+	AutoOutOfRange { index: usize, count: usize },
+	#[error("no variant could be selected (tile has no variants or invalid weights)")]
+	NoVariant,
+	#[error("no auto tile could be selected (tile has no auto tiles)")]
+	NoAutoTiles,
+}
+
+/// An inconsistency found while validating a tileset
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+	#[error("tile {name:?} maps to group ID {group_id:?}, which has no name")]
+	MissingName { name: String, group_id: TileGroupId },
+	#[error("tile {name:?} maps to group ID {group_id:?}, which is named {found:?}")]
+	NameMismatch {
+		name: String,
+		group_id: TileGroupId,
+		found: String,
+	},
+	#[error("multiple names map to group ID {group_id:?}: {names:?}")]
+	DuplicateGroup {
+		group_id: TileGroupId,
+		names: Vec<String>,
+	},
+	#[error("group ID {0:?} is named but has no tile data")]
+	MissingTile(TileGroupId),
+	#[error("atlas index {index} belongs to group ID {group_id:?}, which has no tile data")]
+	UnknownIndexGroup { index: usize, group_id: TileGroupId },
+	#[error("atlas index {0} is outside of the atlas")]
+	IndexOutOfBounds(usize),
+	#[error("texture atlas could not be found")]
+	MissingAtlas,
+	#[error("auto tile with group ID {group_id:?} falls back to unknown tile {name:?}")]
+	UnknownFallbackTile { group_id: TileGroupId, name: String },
 }
@@ -0,0 +1,88 @@
+//! Fixtures shared by the tileset tests
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use bevy::prelude::{Handle, Image, Rect, TextureAtlas, Vec2};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_tileset_tiles::prelude::*;
+
+pub(crate) use super::builder::MemoryTextureStore;
+use crate::prelude::{RawTileset, TileGroupId, TileId};
+
+/// Creates a 2x2 image filled with the given pixel
+pub(crate) fn image(pixel: [u8; 4]) -> Image {
+	Image::new_fill(
+		Extent3d {
+			width: 2,
+			height: 2,
+			depth_or_array_layers: 1,
+		},
+		TextureDimension::D2,
+		&pixel,
+		TextureFormat::Rgba8UnormSrgb,
+	)
+}
+
+/// Creates a tileset from the given tiles, mapped by their group ID
+///
+/// Every index used by a tile is mapped back to that tile, and the atlas is given a 1x1
+/// texture for each index up to the largest one.
+pub(crate) fn tileset<I: IntoIterator<Item = (TileGroupId, TileData)>>(tiles: I) -> RawTileset {
+	let mut tileset = RawTileset {
+		id: 0,
+		name: String::from("Test Tileset"),
+		tiles: HashMap::new(),
+		size: Vec2::ZERO,
+		tile_size: Vec2::ONE,
+		tile_ids: HashMap::new(),
+		tile_names: HashMap::new(),
+		tile_handles: HashMap::new(),
+		tile_indices: HashMap::new(),
+		empty_tile: None,
+		atlas: TextureAtlas::new_empty(Handle::default(), Vec2::ZERO),
+	};
+
+	for (group_id, tile) in tiles {
+		for index in tile.indices() {
+			tileset
+				.tile_indices
+				.insert(index, TileId::new(group_id, tileset.id));
+		}
+		tileset.tile_ids.insert(tile.name().to_string(), group_id);
+		tileset.tile_names.insert(group_id, tile.name().to_string());
+		tileset.tiles.insert(group_id, tile);
+	}
+
+	let texture_count = tileset
+		.tile_indices
+		.keys()
+		.max()
+		.map_or(0, |index| index + 1);
+	for _ in 0..texture_count {
+		tileset.atlas.add_texture(Rect::new(0.0, 0.0, 1.0, 1.0));
+	}
+
+	tileset
+}
+
+/// Creates a tile spanning the given atlas indices
+///
+/// Tiles spanning multiple indices are animated.
+pub(crate) fn tile(name: &str, indices: RangeInclusive<usize>) -> TileData {
+	let tile = if indices.start() == indices.end() {
+		TileType::Standard(*indices.start())
+	} else {
+		TileType::Animated(AnimatedTileData::new(1.0, *indices.start(), *indices.end()))
+	};
+	TileData::new(name.to_string(), tile)
+}
+
+/// Creates an auto tile with a single standard variant
+#[cfg(feature = "auto-tile")]
+pub(crate) fn auto(rule: AutoTileRule, index: usize) -> AutoTileData {
+	AutoTileData::new(
+		rule,
+		vec![VariantTileData::new(1.0, SimpleTileType::Standard(index))],
+	)
+}
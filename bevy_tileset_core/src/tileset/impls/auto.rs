@@ -1,7 +1,10 @@
 //! Implementation details for Auto Tiles
 
-use crate::prelude::{PartialTileId, RawTileset, TileIndex, Tileset};
+use crate::auto::AutoTileId;
+use crate::prelude::{PartialTileId, RawTileset, SelectError, TileGroupId, TileIndex, Tileset};
+use crate::tileset::rng::default_rng;
 use bevy_tileset_tiles::prelude::*;
+use rand::Rng;
 
 macro_rules! impl_tileset {
 	($name: ident) => {
@@ -54,6 +57,7 @@ macro_rules! impl_tileset {
 			/// for reconstructing tiles with known indices.
 			///
 			/// If the ID has an `auto_index` of `None`, then the auto tile will be chosen based on the given rule.
+			/// Likewise, a `variant_index` of `None` (or one that is out of range) selects a random variant.
 			///
 			/// # Arguments
 			///
@@ -66,12 +70,12 @@ macro_rules! impl_tileset {
 			///
 			/// ```
 			/// # use bevy_tileset_core::prelude::*;
+			/// # use bevy_tileset_tiles::prelude::*;
 			/// fn get_index(tileset: &Tileset) {
-			/// 	let index = tileset.get_auto_index_by_id(PartialTileId {
-			/// 		group_id: 123,
-			/// 		auto_index: Some(2),
-			/// 		variant_index: None
-			/// 	}, AutoTileRule::default());
+			/// 	let index = tileset.get_auto_index_by_id(
+			/// 		PartialTileId::new(123).auto(2),
+			/// 		AutoTileRule::default()
+			/// 	);
 			/// }
 			/// ```
 			pub fn get_auto_index_by_id<TId: Into<PartialTileId>>(
@@ -79,14 +83,238 @@ macro_rules! impl_tileset {
 				id: TId,
 				rule: AutoTileRule,
 			) -> Option<TileIndex> {
-				let id = id.into();
-				let group_id = id.group_id;
-				let data = self.tiles.get(&group_id)?;
+				self.get_auto_index_by_id_with_rng(id, rule, &mut default_rng())
+			}
+
+			/// Like [`get_auto_index_by_id`](Self::get_auto_index_by_id), but uses the given RNG
+			/// to select any variants
+			///
+			/// This allows auto tiles to be chosen deterministically (i.e. by using a seeded RNG).
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the tile
+			/// * `rule`: The rule to match
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<TileIndex>
+			///
+			pub fn get_auto_index_by_id_with_rng<TId: Into<PartialTileId>, R: Rng + ?Sized>(
+				&self,
+				id: TId,
+				rule: AutoTileRule,
+				rng: &mut R,
+			) -> Option<TileIndex> {
+				self.get_tile_index_for_rule_by_id_with_rng(id, rule, rng)
+					.map(|(index, ..)| index)
+			}
+
+			/// Like [`get_auto_index_by_id`](Self::get_auto_index_by_id), but also returns the ID of
+			/// the exact tile that was chosen
+			///
+			/// The returned ID has its `auto_index` (for auto tiles) and `variant_index` (for auto and
+			/// variant tiles) filled in with the actual selections. Storing this ID allows the same
+			/// appearance to be recreated later (i.e. when loading a saved map).
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the tile
+			/// * `rule`: The rule to match
+			///
+			/// returns: Option<(TileIndex, PartialTileId)>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy_tileset_core::prelude::*;
+			/// # use bevy_tileset_tiles::prelude::*;
+			/// fn place(tileset: &Tileset, rule: AutoTileRule) {
+			/// 	let (index, id) = tileset
+			/// 		.get_tile_index_for_rule_by_id(PartialTileId::new(123), rule)
+			/// 		.unwrap();
+			///
+			/// 	// Selecting the returned ID again always gives the same index
+			/// 	let same = tileset.get_auto_index_by_id(id, rule).unwrap();
+			/// 	assert_eq!(index.base_index(), same.base_index());
+			/// }
+			/// ```
+			pub fn get_tile_index_for_rule_by_id<TId: Into<PartialTileId>>(
+				&self,
+				id: TId,
+				rule: AutoTileRule,
+			) -> Option<(TileIndex, PartialTileId)> {
+				self.get_tile_index_for_rule_by_id_with_rng(id, rule, &mut default_rng())
+			}
 
-				match data.tile() {
-					TileType::Auto(autos) => Self::select_auto(autos, rule, id),
-					_ => self.get_tile_index_by_id(id),
+			/// Like [`get_tile_index_for_rule_by_id`](Self::get_tile_index_for_rule_by_id), but uses
+			/// the given RNG to select any variants
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the tile
+			/// * `rule`: The rule to match
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<(TileIndex, PartialTileId)>
+			///
+			pub fn get_tile_index_for_rule_by_id_with_rng<
+				TId: Into<PartialTileId>,
+				R: Rng + ?Sized,
+			>(
+				&self,
+				id: TId,
+				rule: AutoTileRule,
+				rng: &mut R,
+			) -> Option<(TileIndex, PartialTileId)> {
+				let mut id = id.into();
+				let data = self.tiles.get(&id.group_id)?;
+
+				let variants = match data.tile() {
+					TileType::Auto(autos) => {
+						let auto_index = match id.auto_index {
+							Some(index) => index,
+							None => {
+								if let Some(index) = self.get_fallback_tile_index(autos, &rule) {
+									return Some((index, id));
+								}
+								Self::match_auto_index(autos, &rule)?
+							},
+						};
+						id.auto_index = Some(auto_index);
+						autos.get(auto_index)?.variants()
+					},
+					TileType::Variant(variants) => variants,
+					_ => return Some((self.get_tile_index_by_id(id)?, id)),
+				};
+
+				let variant_index = Self::resolve_variant_index(variants, id.variant_index, rng)?;
+				id.variant_index = Some(variant_index);
+				let variant = variants.get(variant_index)?;
+
+				Some((variant.tile().into(), id))
+			}
+
+			/// Gets the [`TileIndex`] for the given auto tile, along with its resolved ID
+			///
+			/// The auto tile is always chosen based on the given rule. However, if the ID has a `variant_index`,
+			/// that variant will be chosen again (if it exists in the matching auto tile). Otherwise, a random
+			/// variant is chosen.
+			///
+			/// The returned ID contains the index of the chosen variant, and should be stored so that the same
+			/// variant is chosen the next time this tile is evaluated.
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the auto tile
+			/// * `rule`: The rule to match
+			///
+			/// returns: Option<(TileIndex, AutoTileId)>
+			///
+			pub fn resolve_auto_tile(
+				&self,
+				id: &AutoTileId,
+				rule: AutoTileRule,
+			) -> Option<(TileIndex, AutoTileId)> {
+				self.resolve_auto_tile_with_rng(id, rule, &mut default_rng())
+			}
+
+			/// Like [`resolve_auto_tile`](Self::resolve_auto_tile), but uses the given RNG
+			/// to select a new variant
+			///
+			/// This allows auto tiles to be resolved deterministically (i.e. by using a seeded RNG).
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the auto tile
+			/// * `rule`: The rule to match
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<(TileIndex, AutoTileId)>
+			///
+			pub fn resolve_auto_tile_with_rng<R: Rng + ?Sized>(
+				&self,
+				id: &AutoTileId,
+				rule: AutoTileRule,
+				rng: &mut R,
+			) -> Option<(TileIndex, AutoTileId)> {
+				let data = self.tiles.get(&id.group_id)?;
+				let autos = match data.tile() {
+					TileType::Auto(autos) => autos,
+					_ => return None,
+				};
+				if let Some(index) = self.get_fallback_tile_index(autos, &rule) {
+					return Some((index, *id));
 				}
+
+				let auto = Self::match_auto(autos, &rule)?;
+				let variant_index =
+					Self::resolve_variant_index(auto.variants(), id.variant_index, rng)?;
+				let variant = auto.variants().get(variant_index)?;
+
+				Some((
+					variant.tile().into(),
+					AutoTileId {
+						variant_index: Some(variant_index),
+						..*id
+					},
+				))
+			}
+
+			/// Iterate over all auto tiles in this tileset along with their group IDs
+			///
+			/// Each item contains the auto tile's rule entries in the order they were defined. To
+			/// enumerate the rules and variants of a single auto tile, see [`iter_auto_rules`](Self::iter_auto_rules).
+			pub fn iter_auto_tiles(&self) -> impl Iterator<Item = (TileGroupId, &[AutoTileData])> {
+				self.tiles
+					.iter()
+					.filter_map(|(group_id, data)| match data.tile() {
+						TileType::Auto(autos) => Some((*group_id, autos.as_slice())),
+						_ => None,
+					})
+			}
+
+			/// Iterate over the rule entries of the auto tile with the given group ID
+			///
+			/// Each item contains the entry's auto index, its rule, and its variants. The auto index
+			/// (along with the index of a variant) can be used to construct a [`PartialTileId`]
+			/// for this specific entry.
+			///
+			/// Returns `None` if the tile does not exist or is not an auto tile.
+			///
+			/// # Arguments
+			///
+			/// * `group_id`: The group ID of the auto tile
+			///
+			/// returns: Option<impl Iterator<Item = (usize, AutoTileRule, &[VariantTileData])>>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy_tileset_core::prelude::*;
+			/// fn print_rules(tileset: &Tileset) {
+			/// 	for (group_id, ..) in tileset.iter_auto_tiles() {
+			/// 		for (auto_index, rule, variants) in tileset.iter_auto_rules(&group_id).unwrap() {
+			/// 			let count = variants.len();
+			/// 			println!("{} -> rule #{} ({:?}) -> {} variants", group_id, auto_index, rule, count);
+			/// 		}
+			/// 	}
+			/// }
+			/// ```
+			pub fn iter_auto_rules(
+				&self,
+				group_id: &TileGroupId,
+			) -> Option<impl Iterator<Item = (usize, AutoTileRule, &[VariantTileData])>> {
+				let autos = match self.tiles.get(group_id)?.tile() {
+					TileType::Auto(autos) => autos,
+					_ => return None,
+				};
+
+				Some(
+					autos
+						.iter()
+						.enumerate()
+						.map(|(index, auto)| (index, auto.rule(), auto.variants().as_slice())),
+				)
 			}
 
 			/// Checks if the given index is a variant for a given auto tile rule
@@ -108,9 +336,14 @@ macro_rules! impl_tileset {
 			/// returns: bool
 			pub fn is_auto_variant(&self, name: &str, index: &usize, rule: &AutoTileRule) -> bool {
 				if let Some(data) = self.get_tile_data(name) {
+					if !data.contains_index(index) {
+						// Index isn't used anywhere in this tile
+						return false;
+					}
+
 					match data.tile() {
 						TileType::Auto(autos) => {
-							if let Some(auto) = autos.iter().find(|a| a.rule().is_subset_of(rule)) {
+							if let Some(auto) = Self::match_auto(autos, rule) {
 								// Check if _any_ variant matches the given index
 								auto.variants()
 									.iter()
@@ -118,7 +351,7 @@ macro_rules! impl_tileset {
 							} else {
 								false
 							}
-						}
+						},
 						_ => false,
 					}
 				} else {
@@ -126,31 +359,95 @@ macro_rules! impl_tileset {
 				}
 			}
 
-			pub(crate) fn select_auto<TId: Into<PartialTileId>>(
+			pub(crate) fn try_select_auto<TId: Into<PartialTileId>, R: Rng + ?Sized>(
 				auto_tiles: &[AutoTileData],
 				rule: AutoTileRule,
 				id: TId,
-			) -> Option<TileIndex> {
+				rng: &mut R,
+			) -> Result<TileIndex, SelectError> {
 				let id = id.into();
-				let tile = if let Some(idx) = id.auto_index {
-					auto_tiles.get(idx)?
+				let tile = if let Some(index) = id.auto_index {
+					auto_tiles.get(index).ok_or(SelectError::AutoOutOfRange {
+						index,
+						count: auto_tiles.len(),
+					})?
 				} else {
-					match auto_tiles
-						.iter()
-						.find(|&auto| auto.rule().is_subset_of(&rule))
-					{
-						Some(t) => t,
-						None => auto_tiles.last()?,
-					}
+					Self::match_auto(auto_tiles, &rule).ok_or(SelectError::NoAutoTiles)?
 				};
 
-				let variant = if let Some(idx) = id.variant_index {
-					tile.variants().get(idx)?
-				} else {
-					Self::select_variant(tile.variants())?
-				};
+				let variant = Self::try_get_variant(tile.variants(), id.variant_index, rng)?;
+				Ok(variant.tile().into())
+			}
+
+			/// Returns the given variant index if it exists, otherwise selects a new one at random
+			///
+			/// This allows stored indices to outlive changes to the tileset (i.e. variants being removed).
+			fn resolve_variant_index<R: Rng + ?Sized>(
+				variants: &[VariantTileData],
+				index: Option<usize>,
+				rng: &mut R,
+			) -> Option<usize> {
+				match index {
+					Some(index) if index < variants.len() => Some(index),
+					_ => Self::select_variant_index_with_rng(variants, rng),
+				}
+			}
+
+			/// Finds the first auto tile matching the given rule
+			///
+			/// Rules matched by the [isolated](AutoTileRule::isolated) rule (i.e. those without any cardinal
+			/// connections) prefer the isolated auto tile (if any).
+			/// If none match, the auto tile marked as the fallback is used (or the last auto tile if none are).
+			///
+			/// Since this only has access to the auto tiles themselves, any `fallback_tile` is ignored.
+			pub(crate) fn match_auto<'a>(
+				auto_tiles: &'a [AutoTileData],
+				rule: &AutoTileRule,
+			) -> Option<&'a AutoTileData> {
+				auto_tiles.get(Self::match_auto_index(auto_tiles, rule)?)
+			}
+
+			/// Finds the index of the first auto tile matching the given rule (see [`match_auto`](Self::match_auto))
+			pub(crate) fn match_auto_index(
+				auto_tiles: &[AutoTileData],
+				rule: &AutoTileRule,
+			) -> Option<usize> {
+				Self::find_auto_index(auto_tiles, rule).or_else(|| auto_tiles.len().checked_sub(1))
+			}
+
+			/// Like [`match_auto_index`](Self::match_auto_index), but returns `None` instead of
+			/// falling back to the last auto tile
+			fn find_auto_index(auto_tiles: &[AutoTileData], rule: &AutoTileRule) -> Option<usize> {
+				let isolated = AutoTileRule::isolated();
+				if isolated.is_subset_of(rule) {
+					let index = auto_tiles.iter().position(|auto| auto.rule() == isolated);
+					if index.is_some() {
+						return index;
+					}
+				}
+
+				auto_tiles
+					.iter()
+					.position(|auto| auto.rule().is_subset_of(rule))
+					.or_else(|| auto_tiles.iter().position(|auto| auto.is_fallback()))
+			}
+
+			/// Gets the index of the fallback tile named by the given auto tiles, if none of them match the rule
+			///
+			/// The fallback tile is selected just like any other tile (see
+			/// [`get_tile_index_by_id`](Self::get_tile_index_by_id)).
+			fn get_fallback_tile_index(
+				&self,
+				auto_tiles: &[AutoTileData],
+				rule: &AutoTileRule,
+			) -> Option<TileIndex> {
+				if Self::find_auto_index(auto_tiles, rule).is_some() {
+					return None;
+				}
 
-				Some(variant.tile().into())
+				let name = auto_tiles.iter().find_map(|auto| auto.fallback_tile())?;
+				let group_id = self.get_tile_group_id(name)?;
+				self.get_tile_index_by_id(*group_id)
 			}
 		}
 	};
@@ -158,3 +455,134 @@ macro_rules! impl_tileset {
 
 impl_tileset!(Tileset);
 impl_tileset!(RawTileset);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::{TilesetRng, ValidationError};
+	use crate::tileset::fixtures::{auto, tile, tileset};
+
+	#[test]
+	fn should_select_isolated_without_cardinal_neighbors() {
+		let autos = vec![
+			auto(AutoTileRule::default(), 0),
+			auto(AutoTileRule::isolated(), 1),
+		];
+		let tileset = tileset([(
+			0,
+			TileData::new(String::from("Wall"), TileType::Auto(autos)),
+		)]);
+
+		// Diagonal neighbors are ignored by the isolated rule
+		let rule = AutoTileRule {
+			north_east: Some(true),
+			south_west: Some(true),
+			..Default::default()
+		};
+		let index = tileset.get_auto_index("Wall", rule).unwrap();
+		assert_eq!(1, *index.base_index());
+
+		let rule = AutoTileRule {
+			north: Some(true),
+			..Default::default()
+		};
+		let index = tileset.get_auto_index("Wall", rule).unwrap();
+		assert_eq!(0, *index.base_index());
+	}
+	#[test]
+	fn should_round_trip_tile_index_for_rule() {
+		let variants = (0..4)
+			.map(|index| VariantTileData::new(1.0, SimpleTileType::Standard(index)))
+			.collect();
+		let autos = vec![
+			AutoTileData::new(AutoTileRule::default(), variants),
+			auto(AutoTileRule::isolated(), 4),
+		];
+		let tileset = tileset([(
+			0,
+			TileData::new(String::from("Wall"), TileType::Auto(autos)),
+		)]);
+		let rule = AutoTileRule {
+			north: Some(true),
+			..Default::default()
+		};
+
+		let mut rng = TilesetRng::from_seed(123);
+		for _ in 0..8 {
+			let (index, id) = tileset
+				.get_tile_index_for_rule_by_id_with_rng(PartialTileId::new(0), rule, &mut rng)
+				.unwrap();
+			assert_eq!(Some(0), id.auto_index);
+
+			let same = tileset
+				.get_auto_index_by_id_with_rng(id, rule, &mut rng)
+				.unwrap();
+			assert_eq!(index.base_index(), same.base_index());
+		}
+
+		// Out of range variants are selected again, just like when resolving an auto tile
+		let id = PartialTileId::new(0).auto(0).variant(10);
+		let (index, id) = tileset
+			.get_tile_index_for_rule_by_id_with_rng(id, rule, &mut rng)
+			.unwrap();
+		assert_eq!(Some(*index.base_index()), id.variant_index);
+	}
+
+	#[test]
+	fn should_fall_back_to_named_tile() {
+		let autos =
+			vec![auto(AutoTileRule::isolated(), 0).with_fallback_tile(Some(String::from("Floor")))];
+		let wall = TileData::new(String::from("Wall"), TileType::Auto(autos));
+		let tileset = tileset([(0, wall), (1, tile("Floor", 1..=1))]);
+		assert!(tileset.validate().is_ok());
+
+		let rule = AutoTileRule {
+			north: Some(true),
+			..Default::default()
+		};
+		let index = tileset
+			.get_auto_index_by_id(PartialTileId::new(0), rule)
+			.unwrap();
+		assert_eq!(1, *index.base_index());
+
+		let id = AutoTileId::new(0, 0);
+		let (index, ..) = tileset.resolve_auto_tile(&id, rule).unwrap();
+		assert_eq!(1, *index.base_index());
+
+		// Matching rules are unaffected
+		let index = tileset
+			.get_auto_index_by_id(PartialTileId::new(0), AutoTileRule::isolated())
+			.unwrap();
+		assert_eq!(0, *index.base_index());
+	}
+
+	#[test]
+	fn should_reject_unknown_fallback_tile() {
+		let autos = vec![
+			auto(AutoTileRule::isolated(), 0).with_fallback_tile(Some(String::from("Missing")))
+		];
+		let tileset = tileset([(
+			0,
+			TileData::new(String::from("Wall"), TileType::Auto(autos)),
+		)]);
+
+		assert_eq!(
+			Err(vec![ValidationError::UnknownFallbackTile {
+				group_id: 0,
+				name: String::from("Missing"),
+			}]),
+			tileset.validate()
+		);
+	}
+
+	#[test]
+	fn should_not_select_without_auto_tiles() {
+		let result = RawTileset::try_select_auto(
+			&[],
+			AutoTileRule::default(),
+			PartialTileId::new(0),
+			&mut TilesetRng::from_seed(123),
+		);
+		assert!(matches!(result, Err(SelectError::NoAutoTiles)));
+	}
+}
@@ -0,0 +1,153 @@
+//! Implementation details for comparing two tilesets
+
+use std::collections::HashMap;
+
+use crate::prelude::{RawTileset, TileGroupId, Tileset};
+
+/// The differences between two versions of a tileset (see [`Tileset::diff`])
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TilesetDiff {
+	/// The group IDs of tiles that only exist in the new tileset (sorted in ascending order)
+	pub added: Vec<TileGroupId>,
+	/// The group IDs of tiles that only exist in the old tileset (sorted in ascending order)
+	pub removed: Vec<TileGroupId>,
+	/// The tiles whose name changed, mapped by their group ID to their `(old, new)` names
+	pub renamed: HashMap<TileGroupId, (String, String)>,
+	/// The new atlas index for every old atlas index that moved
+	///
+	/// Indices that stayed in place (or whose tile was removed) are not included.
+	pub moved: HashMap<usize, usize>,
+}
+
+impl TilesetDiff {
+	/// Returns true if the two tilesets contain the same tiles at the same atlas indices
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty()
+			&& self.removed.is_empty()
+			&& self.renamed.is_empty()
+			&& self.moved.is_empty()
+	}
+}
+
+macro_rules! impl_tileset {
+	($name: ident) => {
+		impl $name {
+			/// Compares this tileset against a newer version of it
+			///
+			/// This is useful for patching tiles that have already been placed after a tileset is
+			/// reloaded, rather than rebuilding everything from scratch. Tiles are matched by their
+			/// group ID.
+			///
+			/// # Arguments
+			///
+			/// * `other`: The newer version of this tileset
+			///
+			/// returns: TilesetDiff
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy_tileset_core::prelude::*;
+			///
+			/// fn on_reload(old_tileset: &Tileset, new_tileset: &Tileset) {
+			/// 	let diff = old_tileset.diff(new_tileset);
+			/// 	for (old_index, new_index) in &diff.moved {
+			/// 		// Update placed tiles using `old_index`...
+			/// 	}
+			/// }
+			/// ```
+			pub fn diff(&self, other: &Self) -> TilesetDiff {
+				let mut added = other
+					.tiles
+					.keys()
+					.filter(|group_id| !self.tiles.contains_key(group_id))
+					.copied()
+					.collect::<Vec<_>>();
+				added.sort_unstable();
+
+				let mut removed = self
+					.tiles
+					.keys()
+					.filter(|group_id| !other.tiles.contains_key(group_id))
+					.copied()
+					.collect::<Vec<_>>();
+				removed.sort_unstable();
+
+				let renamed = self
+					.tiles
+					.iter()
+					.filter_map(|(group_id, data)| {
+						let new_name = other.tiles.get(group_id)?.name();
+						if data.name() == new_name {
+							None
+						} else {
+							Some((*group_id, (data.name().to_string(), new_name.to_string())))
+						}
+					})
+					.collect();
+
+				let moved = super::repack::remap_indices(&self.tile_indices, &other.tile_indices)
+					.into_iter()
+					.filter(|(old, new)| old != new)
+					.collect();
+
+				TilesetDiff {
+					added,
+					removed,
+					renamed,
+					moved,
+				}
+			}
+		}
+	};
+}
+
+impl_tileset!(Tileset);
+impl_tileset!(RawTileset);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tileset::fixtures::{tile, tileset};
+
+	#[test]
+	fn should_diff_tilesets() {
+		let old = tileset([
+			(0, tile("Grass", 0..=0)),
+			(1, tile("Water", 1..=3)),
+			(2, tile("Dirt", 4..=4)),
+		]);
+		let new = tileset([
+			(1, tile("Water", 0..=2)),
+			(0, tile("Lawn", 3..=3)),
+			(3, tile("Stone", 4..=4)),
+		]);
+
+		let diff = old.diff(&new);
+		assert_eq!(vec![3], diff.added);
+		assert_eq!(vec![2], diff.removed);
+		assert_eq!(
+			HashMap::from([(0, (String::from("Grass"), String::from("Lawn")))]),
+			diff.renamed
+		);
+		// Animation frames keep their order, while the removed tile's index is left out
+		assert_eq!(HashMap::from([(0, 3), (1, 0), (2, 1), (3, 2)]), diff.moved);
+	}
+
+	#[test]
+	fn should_not_move_unchanged_indices() {
+		let old = tileset([(0, tile("Grass", 0..=0)), (1, tile("Water", 1..=3))]);
+		let new = tileset([
+			(0, tile("Grass", 0..=0)),
+			(1, tile("Water", 1..=3)),
+			(2, tile("Dirt", 4..=4)),
+		]);
+
+		let diff = old.diff(&new);
+		assert_eq!(vec![2], diff.added);
+		assert!(diff.removed.is_empty());
+		assert!(diff.moved.is_empty());
+
+		assert!(old.diff(&old).is_empty());
+	}
+}
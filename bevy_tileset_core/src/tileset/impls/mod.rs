@@ -1,9 +1,16 @@
 //! Implementation details for [`Tileset`] and [`RawTileset`]
+//!
+//! Any read-only accessor that does not depend on how the `TextureAtlas` is stored should be
+//! added to the shared `impl_tileset!` macro so that both tileset types expose the same API.
 
-use bevy::prelude::{Handle, Image, TextureAtlas, Vec2};
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::{Assets, Handle, Image, TextureAtlas, Vec2};
+use bevy_tile_atlas::TextureStore;
 
 #[cfg(feature = "auto-tile")]
 pub use auto::*;
+pub use diff::TilesetDiff;
 #[cfg(feature = "variants")]
 pub use variants::*;
 
@@ -12,6 +19,8 @@ use bevy_tileset_tiles::prelude::*;
 
 #[cfg(feature = "auto-tile")]
 mod auto;
+mod diff;
+mod repack;
 #[cfg(feature = "variants")]
 mod variants;
 
@@ -38,6 +47,26 @@ macro_rules! impl_tileset {
 				self.tile_size
 			}
 
+			/// Gets the ID of the tile used to represent an empty cell
+			///
+			/// This can be used to "erase" a tile without relying on a specific tile name.
+			pub fn empty_tile(&self) -> Option<&TileId> {
+				self.empty_tile.as_ref()
+			}
+
+			/// Checks if the given tile is this tileset's empty tile
+			///
+			/// # Arguments
+			///
+			/// * `id`: The tile's ID
+			///
+			/// returns: bool
+			///
+			pub fn is_empty_tile(&self, id: &TileId) -> bool {
+				self.empty_tile
+					.map_or(false, |empty| empty.eq_tile_group(id))
+			}
+
 			/// Get the name of a tile by its group ID
 			///
 			/// # Arguments
@@ -63,6 +92,22 @@ macro_rules! impl_tileset {
 				self.get_tile_name(group_id)
 			}
 
+			/// Get the name of a tile by its ID
+			///
+			/// This is a convenience method around [`get_tile_name`](Self::get_tile_name) for when a
+			/// full or partial tile ID is already on hand.
+			///
+			/// # Arguments
+			///
+			/// * `id`: The tile's ID
+			///
+			/// returns: Option<&str>
+			///
+			pub fn get_tile_name_by_id<TId: Into<PartialTileId>>(&self, id: TId) -> Option<&str> {
+				let id = id.into();
+				self.get_tile_name(&id.group_id).map(String::as_str)
+			}
+
 			/// Get the group ID of a tile by its name
 			///
 			/// # Arguments
@@ -99,6 +144,48 @@ macro_rules! impl_tileset {
 				self.tile_handles.get(index)
 			}
 
+			/// Iterate over the handles of every source image in this tileset (in no particular order)
+			///
+			/// Unless the tileset was built with strong handles (see
+			/// [`TilesetBuilder::with_strong_handles`]), these handles are weak. A strong handle can be
+			/// created from any of them using `Assets::get_handle`, which allows the source images to be
+			/// kept loaded without holding onto the original [`TileHandle`]s.
+			///
+			/// returns: impl Iterator<Item = &Handle<Image>>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy::prelude::*;
+			/// # use bevy_tileset_core::prelude::*;
+			///
+			/// fn pin_images(tileset: &Tileset, images: &Assets<Image>) -> Vec<Handle<Image>> {
+			/// 	tileset.iter_handles().map(|handle| images.get_handle(handle)).collect()
+			/// }
+			/// ```
+			pub fn iter_handles(&self) -> impl Iterator<Item = &Handle<Image>> {
+				self.tile_handles.values()
+			}
+
+			/// Get the number of source image handles in this tileset
+			///
+			/// This is the number of textures in the atlas that came from a tile image.
+			pub fn handle_count(&self) -> usize {
+				self.tile_handles.len()
+			}
+
+			/// Get the data of a tile by its group ID
+			///
+			/// # Arguments
+			///
+			/// * `group_id`: The tile's group ID
+			///
+			/// returns: Option<&TileData>
+			///
+			pub fn get_tile_data_by_id(&self, group_id: &TileGroupId) -> Option<&TileData> {
+				self.tiles.get(group_id)
+			}
+
 			/// Get the data of a tile by its name
 			///
 			/// # Arguments
@@ -112,6 +199,144 @@ macro_rules! impl_tileset {
 				self.tiles.get(id)
 			}
 
+			/// Iterate over all registered tiles and their group IDs
+			pub fn iter_tiles(&self) -> impl Iterator<Item = (&TileGroupId, &TileData)> {
+				self.tiles.iter()
+			}
+
+			/// Gets the number of tiles registered in this tileset
+			pub fn tile_count(&self) -> usize {
+				self.tiles.len()
+			}
+
+			/// Iterate over all atlas indices that are used by a tile
+			///
+			/// The indices are not returned in any particular order.
+			pub fn occupied_indices(&self) -> impl Iterator<Item = usize> + '_ {
+				self.tile_indices.keys().copied()
+			}
+
+			/// Get all indices below `texture_count` that are not used by any tile
+			fn unused_indices_in(&self, texture_count: usize) -> Vec<usize> {
+				(0..texture_count)
+					.filter(|index| !self.tile_indices.contains_key(index))
+					.collect()
+			}
+
+			/// Checks this tileset's internal mappings against an atlas with `texture_count` textures
+			///
+			/// See [`RawTileset::validate`] for the list of checks.
+			fn validate_in(&self, texture_count: usize) -> Result<(), Vec<ValidationError>> {
+				let mut errors = Vec::new();
+
+				let mut names_by_group = HashMap::<TileGroupId, Vec<String>>::new();
+				for (name, group_id) in &self.tile_ids {
+					names_by_group
+						.entry(*group_id)
+						.or_default()
+						.push(name.clone());
+					match self.tile_names.get(group_id) {
+						Some(found) if found != name => {
+							errors.push(ValidationError::NameMismatch {
+								name: name.clone(),
+								group_id: *group_id,
+								found: found.clone(),
+							})
+						},
+						Some(..) => {},
+						None => errors.push(ValidationError::MissingName {
+							name: name.clone(),
+							group_id: *group_id,
+						}),
+					}
+				}
+				for (group_id, mut names) in names_by_group {
+					if names.len() > 1 {
+						names.sort();
+						errors.push(ValidationError::DuplicateGroup { group_id, names });
+					}
+				}
+
+				for group_id in self.tile_names.keys() {
+					if !self.tiles.contains_key(group_id) {
+						errors.push(ValidationError::MissingTile(*group_id));
+					}
+				}
+
+				for (index, id) in &self.tile_indices {
+					if !self.tiles.contains_key(&id.group_id) {
+						errors.push(ValidationError::UnknownIndexGroup {
+							index: *index,
+							group_id: id.group_id,
+						});
+					}
+				}
+				let indices = self
+					.tile_indices
+					.keys()
+					.chain(self.tile_handles.keys())
+					.collect::<HashSet<_>>();
+				for index in indices {
+					if *index >= texture_count {
+						errors.push(ValidationError::IndexOutOfBounds(*index));
+					}
+				}
+
+				#[cfg(feature = "auto-tile")]
+				for (group_id, data) in &self.tiles {
+					if let TileType::Auto(autos) = data.tile() {
+						for name in autos.iter().filter_map(|auto| auto.fallback_tile()) {
+							if !self.tile_ids.contains_key(name) {
+								errors.push(ValidationError::UnknownFallbackTile {
+									group_id: *group_id,
+									name: name.to_string(),
+								});
+							}
+						}
+					}
+				}
+
+				if errors.is_empty() {
+					Ok(())
+				} else {
+					Err(errors)
+				}
+			}
+
+			/// Rebuilds this tileset's atlas so that it only contains the textures of registered tiles
+			/// (see `repack`)
+			///
+			/// Any texture that cannot be found in the `texture_store` (such as those sliced from a
+			/// spritesheet or given to the builder directly) is copied out of the given atlas instead.
+			fn repack_from<TStore: TextureStore>(
+				&self,
+				atlas: &TextureAtlas,
+				texture_store: &mut TStore,
+			) -> Result<(RawTileset, HashMap<usize, usize>), TilesetError> {
+				let mut builder = TilesetBuilder::default().with_strong_handles(true);
+				builder.insert_sliced_textures(repack::recover_textures(
+					&self.tile_handles,
+					atlas,
+					texture_store,
+				)?);
+
+				let mut group_ids = self.tiles.keys().copied().collect::<Vec<_>>();
+				group_ids.sort_unstable();
+				for group_id in group_ids {
+					let tile_handle =
+						repack::get_tile_handle(&self.tiles[&group_id], &self.tile_handles)?;
+					builder.add_tile(tile_handle, group_id, texture_store)?;
+				}
+				builder.set_empty_tile(self.empty_tile.map(|id| id.group_id))?;
+
+				let raw_tileset = builder
+					.build(self.name.clone(), self.id, texture_store)
+					.map_err(TilesetError::AtlasError)?;
+				let remapped = repack::remap_indices(&self.tile_indices, &raw_tileset.tile_indices);
+
+				Ok((raw_tileset, remapped))
+			}
+
 			/// Tries to get the [`TileIndex`] into the `TextureAtlas` for a tile with the given name
 			///
 			/// Auto tiles are given a default rule and will return indices for whatever matches first. To
@@ -139,6 +364,14 @@ macro_rules! impl_tileset {
 				Some(index)
 			}
 
+			/// Tries to get the [`TileIndex`] into the `TextureAtlas` for a tile with the given ID
+			///
+			/// # Arguments
+			///
+			/// * `id`: The ID of the tile
+			///
+			/// returns: Option<TileIndex>
+			///
 			pub fn get_tile_index_by_id<TId: Into<PartialTileId>>(
 				&self,
 				id: TId,
@@ -166,7 +399,72 @@ macro_rules! impl_tileset {
 			pub fn get_base_tile_index(&self, name: &str) -> Option<usize> {
 				match self.get_tile_index(name)? {
 					TileIndex::Standard(index) => Some(index),
-					TileIndex::Animated(start, ..) => Some(start),
+					TileIndex::Animated { start, .. } => Some(start),
+				}
+			}
+
+			/// Gets a representative index into the `TextureAtlas` for the tile with the given ID
+			///
+			/// Unlike [`get_base_tile_index`](Self::get_base_tile_index), this never selects a tile
+			/// at random, making it suitable for things like palettes and previews:
+			///
+			/// * Standard tiles return their index
+			/// * Animated tiles return their first frame
+			/// * Variant tiles return the variant with the highest weight (or the first one on a tie)
+			/// * Auto tiles return the piece matching the default rule (see [`get_tile_index`](Self::get_tile_index))
+			///
+			/// # Arguments
+			///
+			/// * `group_id`: The group ID of the tile
+			///
+			/// returns: Option<usize>
+			///
+			pub fn preview_index(&self, group_id: &TileGroupId) -> Option<usize> {
+				let data = self.tiles.get(group_id)?;
+				match data.tile() {
+					TileType::Standard(index) => Some(*index),
+					TileType::Animated(anim) => Some(anim.start()),
+					#[cfg(feature = "variants")]
+					TileType::Variant(variants) => preview_variant_index(variants),
+					#[cfg(feature = "auto-tile")]
+					TileType::Auto(autos) => {
+						let auto = Self::match_auto(autos, &AutoTileRule::default())?;
+						preview_variant_index(auto.variants())
+					},
+				}
+			}
+
+			/// Get the indices of every animation frame for the tile with the given name
+			///
+			/// All frames are baked into this tileset's single `TextureAtlas`, so every returned
+			/// index is guaranteed to be resident as soon as the tileset itself is loaded. This makes
+			/// it safe to use for preparing animations ahead of time.
+			///
+			/// For Variant and Auto tiles, the frames of all animated variants are returned.
+			///
+			/// # Arguments
+			///
+			/// * `name`: The name of the tile
+			///
+			/// returns: Option<Vec<usize>>
+			///
+			pub fn animation_indices(&self, name: &str) -> Option<Vec<usize>> {
+				let data = self.get_tile_data(name)?;
+				let indices: Vec<usize> = match data.tile() {
+					TileType::Standard(..) => Vec::new(),
+					TileType::Animated(anim) => (anim.start()..=anim.end()).collect(),
+					#[cfg(feature = "variants")]
+					TileType::Variant(variants) => animated_variant_indices(variants.iter()),
+					#[cfg(feature = "auto-tile")]
+					TileType::Auto(autos) => animated_variant_indices(
+						autos.iter().flat_map(|auto| auto.variants().iter()),
+					),
+				};
+
+				if indices.is_empty() {
+					None
+				} else {
+					Some(indices)
 				}
 			}
 
@@ -205,27 +503,48 @@ macro_rules! impl_tileset {
 				&self,
 				tile_id: TId,
 			) -> Option<(TileIndex, &TileData)> {
+				self.try_select_tile_by_id(tile_id).ok()
+			}
+
+			/// Select a tile by its ID, returning the reason for failure if it could not be selected
+			///
+			/// This works exactly like [`select_tile_by_id`](Self::select_tile_by_id), but allows
+			/// an unknown tile group to be distinguished from an out-of-range variant or auto index
+			/// (such as when reconstructing tiles from saved IDs).
+			///
+			/// # Arguments
+			///
+			/// * `tile_id`: The ID of the tile
+			///
+			/// returns: Result<(TileIndex, &TileData), SelectError>
+			///
+			pub fn try_select_tile_by_id<TId: Into<PartialTileId>>(
+				&self,
+				tile_id: TId,
+			) -> Result<(TileIndex, &TileData), SelectError> {
 				let id = tile_id.into();
 				let group_id = id.group_id;
-				let data = self.tiles.get(&group_id)?;
+				let data = self
+					.tiles
+					.get(&group_id)
+					.ok_or(SelectError::UnknownGroup(group_id))?;
 
-				Some((
+				Ok((
 					match data.tile() {
 						TileType::Standard(index) => TileIndex::Standard(*index),
-						TileType::Animated(anim) => {
-							TileIndex::Animated(anim.start(), anim.end(), anim.speed())
-						}
+						TileType::Animated(anim) => anim.into(),
 						#[cfg(feature = "variants")]
 						TileType::Variant(variants) => {
-							let variant = if let Some(idx) = id.variant_index {
-								variants.get(idx)?
-							} else {
-								Self::select_variant(variants)?
-							};
-							variant.tile().into()
-						}
+							let mut rng = crate::tileset::rng::default_rng();
+							Self::try_get_variant(variants, id.variant_index, &mut rng)?
+								.tile()
+								.into()
+						},
 						#[cfg(feature = "auto-tile")]
-						TileType::Auto(autos) => Self::select_auto(autos, AutoTileRule::default(), id)?,
+						TileType::Auto(autos) => {
+							let mut rng = crate::tileset::rng::default_rng();
+							Self::try_select_auto(autos, AutoTileRule::default(), id, &mut rng)?
+						},
 					},
 					data,
 				))
@@ -237,26 +556,302 @@ macro_rules! impl_tileset {
 impl_tileset!(Tileset);
 impl_tileset!(RawTileset);
 
+/// Collects the frame indices of all animated variants
+#[cfg(feature = "variants")]
+fn animated_variant_indices<'a>(variants: impl Iterator<Item = &'a VariantTileData>) -> Vec<usize> {
+	variants
+		.filter_map(|variant| match variant.tile() {
+			SimpleTileType::Animated(anim) => Some(anim.start()..=anim.end()),
+			SimpleTileType::Standard(..) => None,
+		})
+		.flatten()
+		.collect()
+}
+
+/// Gets the base index of the variant with the highest weight (preferring the first on a tie)
+#[cfg(feature = "variants")]
+fn preview_variant_index(variants: &[VariantTileData]) -> Option<usize> {
+	let variant = variants.iter().reduce(|best, variant| {
+		if variant.weight() > best.weight() {
+			variant
+		} else {
+			best
+		}
+	})?;
+	Some(*TileIndex::from(variant.tile()).base_index())
+}
+
 impl RawTileset {
 	/// Gets the tileset `TextureAtlas`
+	///
+	/// A raw tileset owns its atlas, so this returns the atlas itself. This differs from
+	/// [`Tileset::atlas`], which returns a handle to an atlas stored in `Assets<TextureAtlas>`.
 	pub fn atlas(&self) -> &TextureAtlas {
 		&self.atlas
 	}
 
-	/// Gets the handle to the `TextureAtlas`'s texture
+	/// Gets the handle to the `TextureAtlas`'s texture (the single image containing every tile)
+	///
+	/// This is the same as `atlas().texture` and has the same meaning as [`Tileset::texture`].
 	pub fn texture(&self) -> &Handle<Image> {
 		&self.atlas.texture
 	}
+
+	/// Gets the actual size of the texture at the given index in the atlas
+	///
+	/// Unlike [`tile_size`](Self::tile_size), which is the size of each cell in the atlas,
+	/// this is the size of the texture itself.
+	///
+	/// # Arguments
+	///
+	/// * `index`: The texture index
+	///
+	/// returns: Option<Vec2>
+	///
+	pub fn tile_size_at(&self, index: usize) -> Option<Vec2> {
+		self.atlas.textures.get(index).map(|rect| rect.size())
+	}
+
+	/// Get all atlas indices that are not used by any tile
+	///
+	/// This includes the textures left behind by removed tiles, which makes it useful for
+	/// visualizing how fragmented the atlas is.
+	///
+	/// returns: Vec<usize>
+	///
+	pub fn unused_indices(&self) -> Vec<usize> {
+		self.unused_indices_in(self.atlas.len())
+	}
+
+	/// Checks that all of this tileset's internal mappings are consistent with each other
+	///
+	/// This verifies that:
+	/// * Every tile name maps to a group ID with the same name (and vice versa)
+	/// * No two names map to the same group ID
+	/// * Every named group ID has tile data
+	/// * Every atlas index belongs to an existing tile and is within the atlas
+	/// * Every `fallback_tile` of an auto tile names a tile in this tileset
+	///
+	/// This is mostly useful in tests or as a debug check after modifying a tileset at runtime.
+	///
+	/// returns: Result<(), Vec<ValidationError>>
+	///
+	pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+		self.validate_in(self.atlas.len())
+	}
+
+	/// Replaces this tileset's `TextureAtlas` while keeping all of its tile data
+	///
+	/// This is useful for changing the _appearance_ of tiles (i.e. a recolored theme) without
+	/// rebuilding the tileset, so that the indices of placed tiles stay valid. The new atlas must
+	/// contain the same number of textures as the current one, each with a size of [`tile_size`](Self::tile_size).
+	///
+	/// Note that the stored tile handles (see [`get_tile_handle`](Self::get_tile_handle)) are not
+	/// updated, so they will still point to the original images.
+	///
+	/// # Arguments
+	///
+	/// * `atlas`: The new atlas
+	///
+	/// returns: Result<TextureAtlas, TilesetError>
+	///
+	/// The previous atlas is returned on success. On failure, this tileset is left untouched.
+	pub fn replace_atlas(&mut self, atlas: TextureAtlas) -> Result<TextureAtlas, TilesetError> {
+		check_atlas(self.atlas.len(), self.tile_size, &atlas)?;
+		self.size = atlas.size;
+		Ok(std::mem::replace(&mut self.atlas, atlas))
+	}
+
+	/// Rebuilds this tileset's atlas so that it only contains the textures of registered tiles
+	///
+	/// This is useful for reclaiming unused atlas space (see [`unused_indices`](Self::unused_indices)).
+	///
+	/// The tiles are rebuilt from their stored handles. Any texture that cannot be found in the given
+	/// `texture_store` (such as those sliced from a spritesheet) is copied out of the current atlas,
+	/// so only the atlas texture itself is required to exist.
+	///
+	/// # Arguments
+	///
+	/// * `texture_store`: The store of textures
+	///
+	/// returns: Result<(RawTileset, HashMap<usize, usize>), TilesetError>
+	///
+	/// The returned map contains the new atlas index for every old atlas index, which can be used
+	/// to update any tiles that have already been placed.
+	pub fn repack<TStore: TextureStore>(
+		&self,
+		texture_store: &mut TStore,
+	) -> Result<(RawTileset, HashMap<usize, usize>), TilesetError> {
+		self.repack_from(&self.atlas, texture_store)
+	}
 }
 
 impl Tileset {
-	/// Gets the tileset `TextureAtlas`
+	/// Gets the handle to the tileset `TextureAtlas`
+	///
+	/// The atlas itself is stored in `Assets<TextureAtlas>`. This differs from
+	/// [`RawTileset::atlas`], which returns the atlas directly.
+	///
+	/// This handle can be given to a `SpriteSheetBundle` to render tiles using standard Bevy sprites.
 	pub fn atlas(&self) -> &Handle<TextureAtlas> {
 		&self.atlas
 	}
 
-	/// Gets the handle to the `TextureAtlas`'s texture
+	/// Gets the handle to the `TextureAtlas`'s texture (the single image containing every tile)
+	///
+	/// This has the same meaning as [`RawTileset::texture`].
 	pub fn texture(&self) -> &Handle<Image> {
 		&self.texture
 	}
+
+	/// Gets the actual size of the texture at the given index in the atlas
+	///
+	/// Unlike [`tile_size`](Self::tile_size), which is the size of each cell in the atlas,
+	/// this is the size of the texture itself.
+	///
+	/// # Arguments
+	///
+	/// * `index`: The texture index
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Option<Vec2>
+	///
+	pub fn tile_size_at(&self, index: usize, atlases: &Assets<TextureAtlas>) -> Option<Vec2> {
+		let atlas = atlases.get(&self.atlas)?;
+		atlas.textures.get(index).map(|rect| rect.size())
+	}
+
+	/// Get all atlas indices that are not used by any tile
+	///
+	/// This includes the textures left behind by removed tiles, which makes it useful for
+	/// visualizing how fragmented the atlas is.
+	///
+	/// # Arguments
+	///
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Option<Vec<usize>>
+	///
+	/// Returns `None` if the atlas could not be found.
+	pub fn unused_indices(&self, atlases: &Assets<TextureAtlas>) -> Option<Vec<usize>> {
+		let atlas = atlases.get(&self.atlas)?;
+		Some(self.unused_indices_in(atlas.len()))
+	}
+
+	/// Checks that all of this tileset's internal mappings are consistent with each other
+	///
+	/// This performs the same checks as [`RawTileset::validate`], and additionally reports
+	/// the atlas itself being missing.
+	///
+	/// # Arguments
+	///
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Result<(), Vec<ValidationError>>
+	///
+	pub fn validate(&self, atlases: &Assets<TextureAtlas>) -> Result<(), Vec<ValidationError>> {
+		let atlas = atlases
+			.get(&self.atlas)
+			.ok_or_else(|| vec![ValidationError::MissingAtlas])?;
+		self.validate_in(atlas.len())
+	}
+
+	/// Rebuilds this tileset's atlas so that it only contains the textures of registered tiles
+	///
+	/// This is useful for reclaiming unused atlas space (see [`unused_indices`](Self::unused_indices)).
+	///
+	/// The tiles are rebuilt from their stored handles. Any texture that cannot be found in the given
+	/// `texture_store` (such as those sliced from a spritesheet) is copied out of the current atlas,
+	/// so only the atlas and its texture are required to exist.
+	///
+	/// # Arguments
+	///
+	/// * `texture_store`: The store of textures
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Result<(RawTileset, HashMap<usize, usize>), TilesetError>
+	///
+	/// The returned map contains the new atlas index for every old atlas index, which can be used
+	/// to update any tiles that have already been placed.
+	pub fn repack<TStore: TextureStore>(
+		&self,
+		texture_store: &mut TStore,
+		atlases: &Assets<TextureAtlas>,
+	) -> Result<(RawTileset, HashMap<usize, usize>), TilesetError> {
+		let atlas = atlases
+			.get(&self.atlas)
+			.ok_or(TilesetError::AtlasNotFound)?;
+		self.repack_from(atlas, texture_store)
+	}
+
+	/// Replaces this tileset's `TextureAtlas` while keeping all of its tile data
+	///
+	/// This is useful for changing the _appearance_ of tiles (i.e. a recolored theme) without
+	/// rebuilding the tileset, so that the indices of placed tiles stay valid. The new atlas must
+	/// contain the same number of textures as the current one, each with a size of [`tile_size`](Self::tile_size).
+	///
+	/// Note that the stored tile handles (see [`get_tile_handle`](Self::get_tile_handle)) are not
+	/// updated, so they will still point to the original images.
+	///
+	/// # Arguments
+	///
+	/// * `handle`: The handle to the new atlas
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Result<Handle<TextureAtlas>, TilesetError>
+	///
+	/// The previous atlas handle is returned on success. On failure, this tileset is left untouched.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn apply_theme(
+	/// 	tileset: &mut Tileset,
+	/// 	themed: Handle<TextureAtlas>,
+	/// 	atlases: &Assets<TextureAtlas>,
+	/// ) -> Result<(), TilesetError> {
+	/// 	tileset.replace_atlas(themed, atlases)?;
+	/// 	Ok(())
+	/// }
+	/// ```
+	pub fn replace_atlas(
+		&mut self,
+		handle: Handle<TextureAtlas>,
+		atlases: &Assets<TextureAtlas>,
+	) -> Result<Handle<TextureAtlas>, TilesetError> {
+		let atlas = atlases.get(&handle).ok_or(TilesetError::AtlasNotFound)?;
+		let current = atlases
+			.get(&self.atlas)
+			.ok_or(TilesetError::AtlasNotFound)?;
+		check_atlas(current.len(), self.tile_size, atlas)?;
+
+		self.size = atlas.size;
+		self.texture = atlas.texture.clone();
+		Ok(std::mem::replace(&mut self.atlas, handle))
+	}
+}
+
+/// Checks that a replacement atlas has as many textures as the one it replaces, all of the given tile size
+fn check_atlas(expected: usize, tile_size: Vec2, atlas: &TextureAtlas) -> Result<(), TilesetError> {
+	if expected != atlas.len() {
+		return Err(TilesetError::AtlasMismatch {
+			expected,
+			found: atlas.len(),
+		});
+	}
+
+	for (index, rect) in atlas.textures.iter().enumerate() {
+		if rect.size() != tile_size {
+			return Err(TilesetError::AtlasTileSize {
+				index,
+				expected: tile_size,
+				found: rect.size(),
+			});
+		}
+	}
+
+	Ok(())
 }
@@ -0,0 +1,198 @@
+//! Helpers for rebuilding a tileset's atlas (see `repack`)
+
+use std::collections::HashMap;
+
+use bevy::asset::HandleId;
+use bevy::prelude::{Handle, Image, TextureAtlas};
+use bevy_tile_atlas::TextureStore;
+
+use crate::prelude::*;
+use crate::tileset::builder::crop_texture;
+use bevy_tileset_tiles::prelude::*;
+
+/// Recreates the [`TileHandle`] for the given tile using the handles stored in a tileset
+pub(super) fn get_tile_handle(
+	data: &TileData,
+	handles: &HashMap<usize, Handle<Image>>,
+) -> Result<TileHandle, TilesetError> {
+	let handle = match data.tile() {
+		TileType::Standard(index) => {
+			TileHandle::new_standard(data.name(), get_handle(index, handles)?)
+		},
+		TileType::Animated(anim) => {
+			TileHandle::new_animated(data.name(), get_animated(anim, handles)?)
+		},
+		#[cfg(feature = "variants")]
+		TileType::Variant(variants) => {
+			TileHandle::new_variant(data.name(), get_variants(variants, handles)?)
+		},
+		#[cfg(feature = "auto-tile")]
+		TileType::Auto(autos) => TileHandle::new_auto(
+			data.name(),
+			autos
+				.iter()
+				.map(|auto| {
+					Ok(AutoTileHandle {
+						rule: auto.rule(),
+						variants: get_variants(auto.variants(), handles)?,
+						fallback: auto.is_fallback(),
+						fallback_tile: auto.fallback_tile().map(String::from),
+					})
+				})
+				.collect::<Result<_, TilesetError>>()?,
+		),
+	};
+
+	Ok(handle
+		.with_flip(data.flip())
+		.with_sfx(data.sfx().map(String::from)))
+}
+
+/// Copies the textures that cannot be found in the given store out of the atlas
+///
+/// This allows tiles whose textures never existed as assets (such as those sliced from a
+/// spritesheet) to be rebuilt from the atlas they were packed into.
+///
+/// returns: Result<HashMap<HandleId, Image>, TilesetError>
+pub(super) fn recover_textures<TStore: TextureStore>(
+	handles: &HashMap<usize, Handle<Image>>,
+	atlas: &TextureAtlas,
+	texture_store: &TStore,
+) -> Result<HashMap<HandleId, Image>, TilesetError> {
+	let mut recovered = HashMap::new();
+	for (index, handle) in handles {
+		if texture_store.get(handle).is_some() {
+			continue;
+		}
+
+		let texture = texture_store
+			.get(&atlas.texture)
+			.ok_or(TilesetError::ImageNotFound)?;
+		let rect = atlas
+			.textures
+			.get(*index)
+			.ok_or(TilesetError::InvalidData {
+				expected: format!("A texture at atlas index {}", index),
+				found: format!("An atlas with {} textures", atlas.len()),
+			})?;
+		let image = crop_texture(texture, rect.min.as_uvec2(), rect.size().as_uvec2())?;
+		recovered.insert(handle.id(), image);
+	}
+
+	Ok(recovered)
+}
+
+/// Maps each old atlas index to its new atlas index
+///
+/// Indices are matched by their [`TileId`]. Since animation frames share an ID, they are
+/// matched by their relative order instead.
+pub(super) fn remap_indices(
+	old_indices: &HashMap<usize, TileId>,
+	new_indices: &HashMap<usize, TileId>,
+) -> HashMap<usize, usize> {
+	let old_groups = group_indices(old_indices);
+	let mut new_groups = group_indices(new_indices);
+
+	old_groups
+		.into_iter()
+		.filter_map(|(id, old)| Some((old, new_groups.remove(&id)?)))
+		.flat_map(|(old, new)| old.into_iter().zip(new))
+		.collect()
+}
+
+/// Groups the given indices by their tile ID (sorted in ascending order)
+fn group_indices(indices: &HashMap<usize, TileId>) -> HashMap<TileId, Vec<usize>> {
+	let mut groups = HashMap::<TileId, Vec<usize>>::new();
+	for (index, id) in indices {
+		groups.entry(*id).or_default().push(*index);
+	}
+	for group in groups.values_mut() {
+		group.sort_unstable();
+	}
+	groups
+}
+
+fn get_handle(
+	index: &usize,
+	handles: &HashMap<usize, Handle<Image>>,
+) -> Result<Handle<Image>, TilesetError> {
+	handles
+		.get(index)
+		.cloned()
+		.ok_or(TilesetError::ImageNotFound)
+}
+
+fn get_animated(
+	anim: &AnimatedTileData,
+	handles: &HashMap<usize, Handle<Image>>,
+) -> Result<AnimatedTileHandle, TilesetError> {
+	Ok(AnimatedTileHandle {
+		speed: anim.speed(),
+		frames: (anim.start()..=anim.end())
+			.map(|index| get_handle(&index, handles))
+			.collect::<Result<_, _>>()?,
+		sheet: None,
+		indices: None,
+	})
+}
+
+#[cfg(feature = "variants")]
+fn get_variants(
+	variants: &[VariantTileData],
+	handles: &HashMap<usize, Handle<Image>>,
+) -> Result<Vec<VariantTileHandle>, TilesetError> {
+	variants
+		.iter()
+		.map(|variant| {
+			Ok(VariantTileHandle {
+				weight: variant.weight(),
+				tile: match variant.tile() {
+					SimpleTileType::Standard(index) => {
+						SimpleTileHandle::Standard(get_handle(index, handles)?)
+					},
+					SimpleTileType::Animated(anim) => {
+						SimpleTileHandle::Animated(get_animated(anim, handles)?)
+					},
+				},
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tileset::fixtures::{image, MemoryTextureStore};
+
+	fn pixels_at(tileset: &RawTileset, store: &MemoryTextureStore, index: usize) -> Vec<u8> {
+		let atlas = tileset.atlas();
+		let texture = store.get(&atlas.texture).unwrap();
+		let rect = atlas.textures[index];
+		crop_texture(texture, rect.min.as_uvec2(), rect.size().as_uvec2())
+			.unwrap()
+			.data
+	}
+
+	#[test]
+	fn should_repack_sliced_tiles() {
+		let red = [255, 0, 0, 255];
+		let blue = [0, 0, 255, 255];
+
+		let mut store = MemoryTextureStore::default();
+		let mut builder = TilesetBuilder::default();
+		builder.add_standard_image("Red", 0, image(red)).unwrap();
+		builder.add_standard_image("Blue", 1, image(blue)).unwrap();
+		let tileset = builder.build("Sliced", 0, &mut store).unwrap();
+
+		// Only the atlas texture exists in the store
+		assert_eq!(1, store.images.len());
+
+		let (repacked, remapped) = tileset.repack(&mut store).unwrap();
+		for (name, pixel) in [("Red", red), ("Blue", blue)] {
+			let old_index = *tileset.get_tile_index(name).unwrap().base_index();
+			let new_index = *repacked.get_tile_index(name).unwrap().base_index();
+			assert_eq!(Some(&new_index), remapped.get(&old_index));
+			assert_eq!(pixel.repeat(4), pixels_at(&repacked, &store, new_index));
+		}
+	}
+}
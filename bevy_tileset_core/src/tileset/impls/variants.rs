@@ -1,9 +1,11 @@
 //! Implementation details for Variant Tiles
 
-use crate::prelude::{RawTileset, Tileset};
+use crate::prelude::{RawTileset, SelectError, TileGroupId, TileId, Tileset};
+use crate::tileset::rng::default_rng;
+use bevy::math::IVec2;
 use bevy_tileset_tiles::prelude::*;
 use rand::distributions::{Distribution, WeightedIndex};
-use rand::thread_rng;
+use rand::Rng;
 
 macro_rules! impl_tileset {
 	($name: ident) => {
@@ -16,15 +18,418 @@ macro_rules! impl_tileset {
 			///
 			/// returns: Option<&VariantTileData>
 			pub fn select_variant(variants: &[VariantTileData]) -> Option<&VariantTileData> {
-				let mut rng = thread_rng();
+				Self::select_variant_with_rng(variants, &mut default_rng())
+			}
+
+			/// Selects a variant from a collection of variants based on their weights, using the given RNG
+			///
+			/// This works just like [`select_variant`](Self::select_variant), but allows the selection to be
+			/// deterministic (i.e. by using a seeded RNG).
+			///
+			/// # Arguments
+			///
+			/// * `variants`: The variants to choose from
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<&VariantTileData>
+			pub fn select_variant_with_rng<'a, R: Rng + ?Sized>(
+				variants: &'a [VariantTileData],
+				rng: &mut R,
+			) -> Option<&'a VariantTileData> {
+				let idx = Self::select_variant_index_with_rng(variants, rng)?;
+				variants.get(idx)
+			}
+
+			/// Randomly selects a variant based on their weights, considering only the variants that pass the given predicate
+			///
+			/// This allows game-specific conditions to decide which variants are eligible before
+			/// the weighted selection is made. The weights of the remaining variants are used as-is.
+			///
+			/// # Arguments
+			///
+			/// * `variants`: The variants to choose from
+			/// * `predicate`: Returns true if the given variant may be selected
+			///
+			/// returns: Option<&VariantTileData>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy_tileset_core::prelude::*;
+			/// # use bevy_tileset_tiles::prelude::*;
+			/// fn select_wall(variants: &[VariantTileData], is_damaged: bool) -> Option<&VariantTileData> {
+			/// 	// Only allow "damaged" variants (those with a low weight) once the wall has been hit
+			/// 	Tileset::select_variant_filtered(variants, |variant| {
+			/// 		is_damaged || variant.weight() >= 1.0
+			/// 	})
+			/// }
+			/// ```
+			pub fn select_variant_filtered<F: FnMut(&VariantTileData) -> bool>(
+				variants: &[VariantTileData],
+				predicate: F,
+			) -> Option<&VariantTileData> {
+				Self::select_variant_filtered_with_rng(variants, predicate, &mut default_rng())
+			}
+
+			/// Selects a variant based on their weights, considering only the variants that pass the given predicate
+			/// and using the given RNG
+			///
+			/// This works just like [`select_variant_filtered`](Self::select_variant_filtered), but allows the
+			/// selection to be deterministic (i.e. by using a seeded RNG).
+			///
+			/// # Arguments
+			///
+			/// * `variants`: The variants to choose from
+			/// * `predicate`: Returns true if the given variant may be selected
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<&VariantTileData>
+			pub fn select_variant_filtered_with_rng<'a, F, R>(
+				variants: &'a [VariantTileData],
+				mut predicate: F,
+				rng: &mut R,
+			) -> Option<&'a VariantTileData>
+			where
+				F: FnMut(&VariantTileData) -> bool,
+				R: Rng + ?Sized,
+			{
+				let eligible: Vec<&VariantTileData> = variants
+					.iter()
+					.filter(|variant| predicate(variant))
+					.collect();
+				let weights: Vec<f32> = eligible.iter().map(|variant| variant.weight()).collect();
+				let dist = WeightedIndex::new(weights).ok()?;
+				eligible.get(dist.sample(rng)).copied()
+			}
+
+			/// Selects the index of a variant from a collection of variants based on their weights, using the given RNG
+			pub(crate) fn select_variant_index_with_rng<R: Rng + ?Sized>(
+				variants: &[VariantTileData],
+				rng: &mut R,
+			) -> Option<usize> {
 				let weights: Vec<f32> = variants.iter().map(|variant| variant.weight()).collect();
 				let dist = WeightedIndex::new(weights).ok()?;
-				let idx = dist.sample(&mut rng);
-				variants.get(idx)
+				Some(dist.sample(rng))
+			}
+
+			/// Gets the variant at the given index, or a random one if no index is given
+			pub(crate) fn try_get_variant<'a, R: Rng + ?Sized>(
+				variants: &'a [VariantTileData],
+				index: Option<usize>,
+				rng: &mut R,
+			) -> Result<&'a VariantTileData, SelectError> {
+				if let Some(index) = index {
+					variants.get(index).ok_or(SelectError::VariantOutOfRange {
+						index,
+						count: variants.len(),
+					})
+				} else {
+					Self::select_variant_with_rng(variants, rng).ok_or(SelectError::NoVariant)
+				}
+			}
+
+			/// Randomly selects a tile from a collection of tile groups based on their weights
+			///
+			/// This works just like variant selection but across _different_ tiles, which makes
+			/// it useful for things like scattering decorations.
+			///
+			/// Candidates that don't exist in this tileset are ignored.
+			///
+			/// # Arguments
+			///
+			/// * `candidates`: The group IDs to choose from, paired with their weights
+			///
+			/// returns: Option<TileId>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy_tileset_core::prelude::*;
+			/// const FLOWER_ID: TileGroupId = 1;
+			/// const ROCK_ID: TileGroupId = 2;
+			///
+			/// fn scatter(tileset: &Tileset) -> Option<TileId> {
+			/// 	// Flowers are twice as likely to be chosen as rocks
+			/// 	tileset.random_tile(&[(FLOWER_ID, 2.0), (ROCK_ID, 1.0)])
+			/// }
+			/// ```
+			pub fn random_tile(&self, candidates: &[(TileGroupId, f32)]) -> Option<TileId> {
+				self.random_tile_with_rng(candidates, &mut default_rng())
+			}
+
+			/// Selects a tile from a collection of tile groups based on their weights, using the given RNG
+			///
+			/// This works just like [`random_tile`](Self::random_tile), but allows the selection to be
+			/// deterministic (i.e. by using a seeded RNG).
+			///
+			/// # Arguments
+			///
+			/// * `candidates`: The group IDs to choose from, paired with their weights
+			/// * `rng`: The random number generator to use
+			///
+			/// returns: Option<TileId>
+			pub fn random_tile_with_rng<R: Rng + ?Sized>(
+				&self,
+				candidates: &[(TileGroupId, f32)],
+				rng: &mut R,
+			) -> Option<TileId> {
+				let candidates: Vec<&(TileGroupId, f32)> = candidates
+					.iter()
+					.filter(|(group_id, ..)| self.tiles.contains_key(group_id))
+					.collect();
+				let weights: Vec<f32> = candidates.iter().map(|(.., weight)| *weight).collect();
+				let dist = WeightedIndex::new(weights).ok()?;
+				let (group_id, ..) = candidates.get(dist.sample(rng))?;
+				Some(TileId::new(*group_id, self.id))
+			}
+
+			/// Selects a variant index for the variant tile with the given name, based on a tile position
+			///
+			/// Rather than being random, the selection is derived from the position (and this tileset's ID),
+			/// so the same cell always selects the same variant, even across runs. Variants are still
+			/// distributed according to their weights.
+			///
+			/// # Arguments
+			///
+			/// * `name`: The name of the variant tile
+			/// * `pos`: The position of the tile
+			///
+			/// returns: Option<usize>
+			///
+			/// # Examples
+			///
+			/// ```
+			/// # use bevy::math::IVec2;
+			/// # use bevy_tileset_core::prelude::*;
+			/// fn place_grass(tileset: &Tileset) {
+			/// 	let a = tileset.select_variant_for_pos("Grass", IVec2::new(3, 7));
+			/// 	let b = tileset.select_variant_for_pos("Grass", IVec2::new(3, 7));
+			/// 	assert_eq!(a, b);
+			/// }
+			/// ```
+			pub fn select_variant_for_pos(&self, name: &str, pos: IVec2) -> Option<usize> {
+				match self.get_tile_data(name)?.tile() {
+					TileType::Variant(variants) => {
+						select_variant_index_by_hash(variants, position_hash(self.id as u64, pos))
+					},
+					_ => None,
+				}
 			}
 		}
 	};
 }
 
+/// Hashes the given position into a well-distributed value
+///
+/// This is a plain SplitMix64 finalizer, so the result is stable across platforms and runs.
+fn position_hash(seed: u64, pos: IVec2) -> u64 {
+	let mut x = seed ^ (((pos.x as u32 as u64) << 32) | pos.y as u32 as u64);
+	x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+	x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+	x ^ (x >> 31)
+}
+
+/// Selects the index of a variant based on its weight, using the given hash in place of a random value
+fn select_variant_index_by_hash(variants: &[VariantTileData], hash: u64) -> Option<usize> {
+	let total: f32 = variants.iter().map(|variant| variant.weight()).sum();
+	if !(total.is_finite() && total > 0.0) {
+		return None;
+	}
+
+	// Use the top 24 bits so that the fraction is exactly representable as an `f32`
+	let fraction = (hash >> 40) as f32 / (1u32 << 24) as f32;
+	let mut target = fraction * total;
+	for (index, variant) in variants.iter().enumerate() {
+		let weight = variant.weight().max(0.0);
+		if target < weight {
+			return Some(index);
+		}
+		target -= weight;
+	}
+
+	// Floating point error may cause us to overshoot the last variant
+	variants.iter().rposition(|variant| variant.weight() > 0.0)
+}
+
 impl_tileset!(Tileset);
 impl_tileset!(RawTileset);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::{TileIndex, TilesetRng};
+	use crate::tileset::fixtures::{tile, tileset};
+
+	fn mixed_variants(standard_weight: f32, animated_weight: f32) -> Vec<VariantTileData> {
+		vec![
+			VariantTileData::new(standard_weight, SimpleTileType::Standard(0)),
+			VariantTileData::new(
+				animated_weight,
+				SimpleTileType::Animated(AnimatedTileData::new(2.5, 1, 3)),
+			),
+			VariantTileData::new(
+				animated_weight,
+				SimpleTileType::Animated(AnimatedTileData::new(8.0, 4, 5)),
+			),
+		]
+	}
+
+	#[test]
+	fn should_select_animated_variant() {
+		let variants = mixed_variants(0.0, 1.0);
+		let mut rng = TilesetRng::from_seed(123);
+
+		for _ in 0..100 {
+			let variant = Tileset::select_variant_with_rng(&variants, &mut rng).unwrap();
+			match TileIndex::from(variant.tile()) {
+				TileIndex::Animated { start, end, speed } => {
+					assert!(matches!((start, end), (1, 3) | (4, 5)));
+					let expected = if start == 1 { 2.5 } else { 8.0 };
+					assert_eq!(expected, speed);
+				},
+				index => panic!("expected an animated index, found {:?}", index),
+			}
+		}
+	}
+
+	#[test]
+	fn should_select_standard_variant() {
+		let variants = mixed_variants(1.0, 0.0);
+		let mut rng = TilesetRng::from_seed(123);
+
+		for _ in 0..100 {
+			let variant = Tileset::select_variant_with_rng(&variants, &mut rng).unwrap();
+			assert!(matches!(
+				TileIndex::from(variant.tile()),
+				TileIndex::Standard(0)
+			));
+		}
+	}
+
+	#[test]
+	fn should_respect_variant_weights() {
+		// Each animation is three times as likely as the standard tile
+		let variants = mixed_variants(1.0, 3.0);
+		let mut rng = TilesetRng::from_seed(123);
+
+		let samples = 7000;
+		let mut counts = [0usize; 3];
+		for _ in 0..samples {
+			let index = Tileset::select_variant_index_with_rng(&variants, &mut rng).unwrap();
+			counts[index] += 1;
+		}
+
+		let expected = [1000.0, 3000.0, 3000.0];
+		for (count, expected) in counts.iter().zip(expected) {
+			let error = (*count as f32 - expected).abs() / expected;
+			assert!(error < 0.1, "expected ~{}, found {}", expected, count);
+		}
+	}
+
+	#[test]
+	fn should_select_filtered_variant() {
+		let variants = mixed_variants(1.0, 1.0);
+		let mut rng = TilesetRng::from_seed(123);
+
+		let is_fast = |variant: &VariantTileData| match variant.tile() {
+			SimpleTileType::Animated(anim) => anim.speed() > 5.0,
+			SimpleTileType::Standard(..) => false,
+		};
+
+		for _ in 0..100 {
+			let variant =
+				Tileset::select_variant_filtered_with_rng(&variants, is_fast, &mut rng).unwrap();
+			let index = TileIndex::from(variant.tile());
+			assert!(matches!(index, TileIndex::Animated { start: 4, .. }));
+			assert_eq!(2, index.frame_count());
+		}
+
+		let none = Tileset::select_variant_filtered_with_rng(&variants, |_| false, &mut rng);
+		assert!(none.is_none());
+	}
+
+	#[test]
+	fn should_select_random_tile() {
+		let tileset = tileset([(0, tile("Flower", 0..=0)), (1, tile("Rock", 1..=1))]);
+
+		// Rocks are three times as likely as flowers, while unknown tiles are ignored
+		let candidates = [(0, 1.0), (1, 3.0), (2, 100.0)];
+		let mut rng = TilesetRng::from_seed(123);
+		let mut counts = [0usize; 2];
+		for _ in 0..4000 {
+			let id = tileset.random_tile_with_rng(&candidates, &mut rng).unwrap();
+			counts[id.group_id as usize] += 1;
+		}
+
+		let expected = [1000.0, 3000.0];
+		for (count, expected) in counts.iter().zip(expected) {
+			let error = (*count as f32 - expected).abs() / expected;
+			assert!(error < 0.1, "expected ~{}, found {}", expected, count);
+		}
+
+		// The same seed always selects the same tiles
+		let select = |seed| {
+			let mut rng = TilesetRng::from_seed(seed);
+			(0..16)
+				.map(|_| tileset.random_tile_with_rng(&candidates, &mut rng).unwrap())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(select(42), select(42));
+
+		assert!(tileset
+			.random_tile_with_rng(&[(2, 1.0)], &mut rng)
+			.is_none());
+	}
+
+	#[test]
+	fn should_hash_positions_stably() {
+		let pos = IVec2::new(3, -7);
+		assert_eq!(position_hash(1, pos), position_hash(1, pos));
+		assert_ne!(position_hash(1, pos), position_hash(2, pos));
+		assert_ne!(position_hash(1, pos), position_hash(1, IVec2::new(-7, 3)));
+
+		let variants = mixed_variants(1.0, 1.0);
+		for x in -10..10 {
+			for y in -10..10 {
+				let hash = position_hash(0, IVec2::new(x, y));
+				assert_eq!(
+					select_variant_index_by_hash(&variants, hash),
+					select_variant_index_by_hash(&variants, hash)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn should_respect_variant_weights_by_hash() {
+		// Each animation is three times as likely as the standard tile
+		let variants = mixed_variants(1.0, 3.0);
+
+		let mut counts = [0usize; 3];
+		for x in 0..70 {
+			for y in 0..100 {
+				let hash = position_hash(0, IVec2::new(x, y));
+				let index = select_variant_index_by_hash(&variants, hash).unwrap();
+				counts[index] += 1;
+			}
+		}
+
+		let expected = [1000.0, 3000.0, 3000.0];
+		for (count, expected) in counts.iter().zip(expected) {
+			let error = (*count as f32 - expected).abs() / expected;
+			assert!(error < 0.1, "expected ~{}, found {}", expected, count);
+		}
+
+		// Variants without any weight are never selected
+		let variants = mixed_variants(0.0, 1.0);
+		for x in 0..100 {
+			let hash = position_hash(0, IVec2::new(x, 0));
+			assert_ne!(Some(0), select_variant_index_by_hash(&variants, hash));
+		}
+		assert_eq!(
+			None,
+			select_variant_index_by_hash(&mixed_variants(0.0, 0.0), 0)
+		);
+	}
+}
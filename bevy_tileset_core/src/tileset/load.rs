@@ -1,6 +1,10 @@
 use bevy::asset::{Asset, AssetPath, AssetServer, Handle};
 use bevy::prelude::{Image, Res};
 use bevy_tileset_tiles::prelude::*;
+use ron::extensions::Extensions;
+use ron::Options;
+
+use crate::prelude::{TilesetDef, TilesetError};
 
 pub trait TextureLoader {
 	fn load_texture<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<Image>;
@@ -18,10 +22,91 @@ impl<'w> TextureLoader for Res<'w, AssetServer> {
 	}
 }
 
+/// The RON options used to parse all tile and tileset definitions
+///
+/// Along with RON's built-in support for comments and trailing commas, this enables the
+/// `implicit_some` extension by default, so that `Option` fields don't need to be wrapped in `Some(...)`.
+pub fn ron_options() -> Options {
+	Options::default().with_default_extension(Extensions::IMPLICIT_SOME)
+}
+
+/// Parse a [`TileDef`] from the bytes of its definition file
+///
+/// This uses the same options as the asset loader (see [`ron_options`]), so any definition that
+/// loads as an asset can also be loaded manually.
+///
+/// This does not run any migrations. To upgrade older files like the asset loader does, use
+/// [`TilesetLoadSettings::parse_tile_def`](crate::prelude::TilesetLoadSettings::parse_tile_def) instead.
+///
+/// Since only the bytes of a single file are given, a definition that inherits from a `base`
+/// definition cannot be resolved and results in [`TilesetError::UnresolvedBase`]. Such definitions
+/// must either be loaded as part of a tileset asset or be merged manually using
+/// [`parse_partial_tile_def`] and [`PartialTileDef::inherit`].
+///
+/// # Arguments
+///
+/// * `bytes`: The contents of the definition file
+///
+/// returns: Result<TileDef, TilesetError>
+///
+pub fn parse_tile_def(bytes: &[u8]) -> Result<TileDef, TilesetError> {
+	let def = parse_partial_tile_def(bytes)?;
+	if let Some(base) = def.base {
+		return Err(TilesetError::UnresolvedBase(base));
+	}
+	complete_tile_def(def)
+}
+
+/// Parse a [`PartialTileDef`] from the bytes of its definition file
+///
+/// Unlike [`parse_tile_def`], this allows fields to be missing so that they can be inherited from
+/// a base definition.
+///
+/// # Arguments
+///
+/// * `bytes`: The contents of the definition file
+///
+/// returns: Result<PartialTileDef, TilesetError>
+///
+pub fn parse_partial_tile_def(bytes: &[u8]) -> Result<PartialTileDef, TilesetError> {
+	ron_options()
+		.from_bytes(bytes)
+		.map_err(TilesetError::InvalidDefinition)
+}
+
+/// Converts a fully inherited [`PartialTileDef`] into a [`TileDef`]
+///
+/// This fails if the definition (along with its bases) is missing either a name or a tile.
+pub(crate) fn complete_tile_def(def: PartialTileDef) -> Result<TileDef, TilesetError> {
+	def.into_def().ok_or_else(|| TilesetError::InvalidData {
+		expected: String::from("A tile definition with a name and a tile"),
+		found: String::from("A tile definition missing either one"),
+	})
+}
+
+/// Parse a [`TilesetDef`] from the bytes of its definition file
+///
+/// This uses the same options as the asset loader (see [`ron_options`]).
+///
+/// # Arguments
+///
+/// * `bytes`: The contents of the definition file
+///
+/// returns: Result<TilesetDef, TilesetError>
+///
+pub fn parse_tileset_def(bytes: &[u8]) -> Result<TilesetDef, TilesetError> {
+	ron_options()
+		.from_bytes(bytes)
+		.map_err(TilesetError::InvalidDefinition)
+}
+
 /// Load the intermediate tile handles from a set of tile definitions
 ///
 /// This can then be used to generate a new [`RawTileset`](crate::tileset::RawTileset)
 ///
+/// Paths are passed to the loader as-is. To replace any path variables as the asset loader would,
+/// use [`TilesetLoadSettings::load_tile_handles`](crate::prelude::TilesetLoadSettings::load_tile_handles).
+///
 /// # Arguments
 ///
 /// * `tiles`: The collection of tile definitions (in their intended order)
@@ -53,12 +138,24 @@ pub fn load_tile_handles<TTiles: IntoIterator<Item = TileDef>, TLoader: TextureL
 		.map(|tile_def| TileHandle {
 			name: tile_def.name.clone(),
 			tile: match &tile_def.tile {
-				TileDefType::Standard(path) => TileHandleType::Standard(
+				TileDefType::Standard(StandardTileDef::Single(path)) => TileHandleType::Standard(
 					asset_loader.load_texture::<Image, &str>(path.as_str()),
 				),
+				#[cfg(feature = "variants")]
+				TileDefType::Standard(StandardTileDef::Multiple(paths)) => TileHandleType::Variant(
+					paths
+						.iter()
+						.map(|path| VariantTileHandle {
+							weight: 1.0,
+							tile: SimpleTileHandle::Standard(
+								asset_loader.load_texture::<Image, &str>(path.as_str()),
+							),
+						})
+						.collect(),
+				),
 				TileDefType::Animated(anim) => {
 					TileHandleType::Animated(load_animated(anim, asset_loader))
-				}
+				},
 				#[cfg(feature = "variants")]
 				TileDefType::Variant(variants) => TileHandleType::Variant(
 					variants
@@ -74,6 +171,8 @@ pub fn load_tile_handles<TTiles: IntoIterator<Item = TileDef>, TLoader: TextureL
 						.collect(),
 				),
 			},
+			flip: tile_def.flip,
+			sfx: tile_def.sfx.clone(),
 		})
 		.collect::<Vec<_>>()
 }
@@ -83,12 +182,18 @@ fn load_animated<TLoader: TextureLoader>(
 	asset_loader: &TLoader,
 ) -> AnimatedTileHandle {
 	AnimatedTileHandle {
-		speed: def.speed,
+		speed: def.frame_rate(),
 		frames: def
 			.frames
 			.iter()
 			.map(|frame| asset_loader.load_texture::<Image, &str>(frame.as_str()))
 			.collect(),
+		sheet: def.sheet.as_ref().map(|sheet| AnimatedSheetHandle {
+			handle: asset_loader.load_texture::<Image, &str>(sheet.path.as_str()),
+			frame_size: sheet.frame_size,
+			frames: sheet.frames,
+		}),
+		indices: def.frame_range(),
 	}
 }
 
@@ -102,10 +207,10 @@ fn load_variant<TLoader: TextureLoader>(
 		tile: match &def.tile {
 			SimpleTileDefType::Standard(path) => {
 				SimpleTileHandle::Standard(asset_loader.load_texture::<Image, &str>(path.as_str()))
-			}
+			},
 			SimpleTileDefType::Animated(anim) => {
 				SimpleTileHandle::Animated(load_animated(anim, asset_loader))
-			}
+			},
 		},
 	}
 }
@@ -113,7 +218,9 @@ fn load_variant<TLoader: TextureLoader>(
 #[cfg(feature = "auto-tile")]
 fn load_auto<TLoader: TextureLoader>(def: &AutoTileDef, asset_loader: &TLoader) -> AutoTileHandle {
 	AutoTileHandle {
-		rule: def.rule,
+		rule: def.effective_rule(),
+		fallback: def.fallback,
+		fallback_tile: def.fallback_tile.clone(),
 		variants: def
 			.variants
 			.iter()
@@ -121,3 +228,27 @@ fn load_auto<TLoader: TextureLoader>(def: &AutoTileDef, asset_loader: &TLoader)
 			.collect(),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_parse_tile_def() {
+		let def = parse_tile_def(br#"(name: "Grass", tile: Standard("grass.png"))"#).unwrap();
+		assert_eq!("Grass", def.name);
+
+		// The version is only read by migrations
+		let def = parse_tile_def(br#"(version: 1, name: "Grass", tile: Standard("grass.png"))"#);
+		assert!(def.is_ok());
+
+		let def = parse_tile_def(br#"(name: "Grass")"#);
+		assert!(matches!(def, Err(TilesetError::InvalidData { .. })));
+	}
+
+	#[test]
+	fn should_reject_unresolved_base() {
+		let def = parse_tile_def(br#"(base: "grass.ron", name: "Tall Grass")"#);
+		assert!(matches!(def, Err(TilesetError::UnresolvedBase(base)) if base == "grass.ron"));
+	}
+}
@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use ron::error::{Position, SpannedError};
+use serde::Deserialize;
+
+use crate::prelude::TilesetError;
+use crate::tileset::load::ron_options;
+
+/// A function that upgrades the contents of a definition file by a single version
+pub type Migration = dyn Fn(String) -> String + Send + Sync;
+
+type MigrationMap = Arc<RwLock<BTreeMap<u32, Arc<Migration>>>>;
+
+/// A registry of migrations used to upgrade old tileset and tile definition files
+///
+/// Definition files may specify the schema `version` they were written for. When loaded, every
+/// migration registered for that version or later is run (in order) on the file's contents
+/// before it is parsed. Files without a `version` are assumed to already use the current schema
+/// and are never migrated.
+///
+/// The `version` field is only read here, so it is ignored by the parsed definitions themselves.
+///
+/// These are accessed using [`TilesetLoadSettings::migrations`](crate::prelude::TilesetLoadSettings::migrations),
+/// so any changes made to them will apply to every tileset loaded afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tileset_core::prelude::*;
+///
+/// fn register_migrations(settings: Res<TilesetLoadSettings>) {
+/// 	// Version 1 called animated frames "images"
+/// 	settings
+/// 		.migrations()
+/// 		.add_tile_migration(1, |contents| contents.replace("images:", "frames:"));
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct TilesetMigrations {
+	tileset: MigrationMap,
+	tile: MigrationMap,
+}
+
+/// The part of a definition file needed to determine its schema version
+#[derive(Deserialize)]
+struct VersionHeader {
+	#[serde(default)]
+	version: Option<u32>,
+}
+
+impl TilesetMigrations {
+	/// Register a migration that upgrades tileset definition files from the given version
+	///
+	/// # Arguments
+	///
+	/// * `from`: The version this migration upgrades from
+	/// * `migration`: The function that upgrades the file's contents to the next version
+	///
+	/// returns: ()
+	///
+	pub fn add_tileset_migration<F: Fn(String) -> String + Send + Sync + 'static>(
+		&self,
+		from: u32,
+		migration: F,
+	) {
+		self.tileset
+			.write()
+			.unwrap()
+			.insert(from, Arc::new(migration));
+	}
+
+	/// Register a migration that upgrades tile definition files from the given version
+	///
+	/// # Arguments
+	///
+	/// * `from`: The version this migration upgrades from
+	/// * `migration`: The function that upgrades the file's contents to the next version
+	///
+	/// returns: ()
+	///
+	pub fn add_tile_migration<F: Fn(String) -> String + Send + Sync + 'static>(
+		&self,
+		from: u32,
+		migration: F,
+	) {
+		self.tile.write().unwrap().insert(from, Arc::new(migration));
+	}
+
+	/// Run all applicable migrations on the contents of a tileset definition file
+	///
+	/// # Arguments
+	///
+	/// * `bytes`: The contents of the definition file
+	///
+	/// returns: Result<Cow<[u8]>, TilesetError>
+	///
+	pub fn migrate_tileset<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>, TilesetError> {
+		migrate(&self.tileset, bytes)
+	}
+
+	/// Run all applicable migrations on the contents of a tile definition file
+	///
+	/// # Arguments
+	///
+	/// * `bytes`: The contents of the definition file
+	///
+	/// returns: Result<Cow<[u8]>, TilesetError>
+	///
+	pub fn migrate_tile<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>, TilesetError> {
+		migrate(&self.tile, bytes)
+	}
+}
+
+/// Runs every migration registered for the file's version (or later) on its contents
+///
+/// The contents are returned unchanged if they don't specify a version. If any migrations are
+/// registered, the contents must be valid UTF-8 and readable as RON in order to find the version.
+fn migrate<'a>(migrations: &MigrationMap, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>, TilesetError> {
+	let migrations = migrations.read().unwrap();
+	if migrations.is_empty() {
+		return Ok(Cow::Borrowed(bytes));
+	}
+
+	let contents = std::str::from_utf8(bytes).map_err(|err| {
+		TilesetError::InvalidDefinition(SpannedError {
+			code: err.into(),
+			position: Position { line: 1, col: 1 },
+		})
+	})?;
+	let header = ron_options()
+		.from_str::<VersionHeader>(contents)
+		.map_err(TilesetError::InvalidDefinition)?;
+	let Some(version) = header.version else {
+		return Ok(Cow::Borrowed(bytes));
+	};
+
+	let mut contents = contents.to_string();
+	for migration in migrations.range(version..).map(|(.., migration)| migration) {
+		contents = migration(contents);
+	}
+
+	Ok(Cow::Owned(contents.into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn migrations() -> TilesetMigrations {
+		let migrations = TilesetMigrations::default();
+		migrations.add_tile_migration(1, |contents| contents.replace("images:", "frames:"));
+		migrations.add_tile_migration(2, |contents| contents.replace("frames:", "textures:"));
+		migrations
+	}
+
+	fn migrate_tile(migrations: &TilesetMigrations, contents: &str) -> String {
+		let bytes = migrations.migrate_tile(contents.as_bytes()).unwrap();
+		String::from_utf8(bytes.into_owned()).unwrap()
+	}
+
+	#[test]
+	fn should_chain_migrations() {
+		let migrations = migrations();
+		assert_eq!(
+			"(version: 1, textures: [])",
+			migrate_tile(&migrations, "(version: 1, images: [])")
+		);
+		assert_eq!(
+			"(version: 2, images: [], textures: [])",
+			migrate_tile(&migrations, "(version: 2, images: [], frames: [])")
+		);
+		assert_eq!(
+			"(version: 3, frames: [])",
+			migrate_tile(&migrations, "(version: 3, frames: [])")
+		);
+	}
+
+	#[test]
+	fn should_not_migrate_unversioned() {
+		let migrations = migrations();
+		let bytes = migrations.migrate_tile(b"(images: [])").unwrap();
+		assert!(matches!(bytes, Cow::Borrowed(b"(images: [])")));
+	}
+
+	#[test]
+	fn should_report_unreadable_header() {
+		let migrations = migrations();
+		assert!(matches!(
+			migrations.migrate_tile(b"(version: 1, images: ["),
+			Err(TilesetError::InvalidDefinition(..))
+		));
+		assert!(matches!(
+			migrations.migrate_tile(b"(version: 1, name: \"\xFF\")"),
+			Err(TilesetError::InvalidDefinition(..))
+		));
+
+		// Nothing needs to be read if there are no migrations
+		let migrations = TilesetMigrations::default();
+		assert!(migrations.migrate_tile(b"(version: 1, images: [").is_ok());
+	}
+}
@@ -5,14 +5,23 @@ use std::collections::HashMap;
 use bevy::prelude::{Component, Handle, Image, TextureAtlas, Vec2};
 use bevy::reflect::{TypeUuid, TypePath};
 
-pub(crate) use asset::TilesetAssetLoader;
-pub use asset::TilesetDef;
+pub use asset::{
+	TilesetAssetLoader, TilesetDef, TilesetEntry, TilesetLoadSettings, TilesetSheetDef,
+};
 pub use builder::TilesetBuilder;
-pub use error::TilesetError;
+#[cfg(feature = "editor")]
+pub use editor::EditableTileset;
+pub use error::{SelectError, TilesetError, ValidationError};
 pub use impls::*;
-pub use load::load_tile_handles;
-pub(crate) use param::TilesetMap;
-pub use param::Tilesets;
+pub use load::{
+	load_tile_handles, parse_partial_tile_def, parse_tile_def, parse_tileset_def, ron_options,
+};
+pub use migration::{Migration, TilesetMigrations};
+#[cfg(feature = "serde")]
+pub use package::TilesetPackage;
+pub use param::{TileAliases, TilesetHandles, TilesetMap, Tilesets};
+#[cfg(feature = "variants")]
+pub use rng::TilesetRng;
 pub use tile_index::TileIndex;
 
 use crate::prelude::*;
@@ -20,11 +29,21 @@ use bevy_tileset_tiles::prelude::*;
 
 mod asset;
 mod builder;
+#[cfg(feature = "editor")]
+mod editor;
 pub mod error;
+#[cfg(test)]
+mod fixtures;
 mod impls;
 mod load;
+mod migration;
+#[cfg(feature = "serde")]
+mod package;
 mod param;
 mod raw;
+#[cfg(feature = "variants")]
+mod rng;
+mod sprite;
 mod tile_index;
 
 macro_rules! define_tileset {
@@ -49,6 +68,8 @@ macro_rules! define_tileset {
 			tile_handles: HashMap<usize, Handle<Image>>,
 			/// The tile IDs mapped by their index in the atlas
 			tile_indices: HashMap<usize, TileId>,
+			/// The ID of the tile used to represent an empty cell (if any)
+			empty_tile: Option<TileId>,
 			$(
 				$(#[$field_attr])*
 				$field : $type
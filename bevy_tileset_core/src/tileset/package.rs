@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bevy::math::Rect;
+use bevy::prelude::{Assets, Image, TextureAtlas, Vec2};
+use bevy::render::texture::{CompressedImageFormats, ImageType};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use bevy_tileset_tiles::prelude::*;
+
+/// A self-contained, serializable snapshot of a [`Tileset`]
+///
+/// This bundles the atlas texture (encoded as a PNG) along with all tile metadata so that
+/// a tileset can be sent somewhere else (i.e. over the network) and rebuilt there
+/// using [`Tileset::from_package`].
+///
+/// This requires the `serde` feature.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TilesetPackage {
+	/// The ID of the tileset
+	pub id: TilesetId,
+	/// The name of the tileset
+	pub name: String,
+	/// The registered tiles mapped by their ID
+	pub tiles: HashMap<TileGroupId, TileData>,
+	/// The size of the tiles in the tileset (in pixels)
+	pub tile_size: [f32; 2],
+	/// The tile group IDs mapped by their name
+	pub tile_ids: HashMap<String, TileGroupId>,
+	/// The tile names mapped by their ID
+	pub tile_names: HashMap<TileGroupId, String>,
+	/// The tile IDs mapped by their index in the atlas
+	pub tile_indices: HashMap<usize, TileId>,
+	/// The ID of the tile used to represent an empty cell (if any)
+	pub empty_tile: Option<TileId>,
+	/// The PNG-encoded atlas texture
+	pub atlas: Vec<u8>,
+	/// The size of the atlas texture (in pixels)
+	pub atlas_size: [f32; 2],
+	/// The rects of each tile in the atlas texture, as `[min_x, min_y, max_x, max_y]`
+	pub texture_rects: Vec<[f32; 4]>,
+}
+
+impl Tileset {
+	/// Packages this tileset into a serializable [`TilesetPackage`]
+	///
+	/// # Arguments
+	///
+	/// * `images`: The world's `Image` assets
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Result<TilesetPackage, TilesetError>
+	///
+	pub fn to_package(
+		&self,
+		images: &Assets<Image>,
+		atlases: &Assets<TextureAtlas>,
+	) -> Result<TilesetPackage, TilesetError> {
+		let atlas = atlases
+			.get(&self.atlas)
+			.ok_or(TilesetError::ImageNotFound)?;
+		let texture = images
+			.get(&self.texture)
+			.ok_or(TilesetError::ImageNotFound)?;
+
+		let mut bytes = Vec::new();
+		texture
+			.clone()
+			.try_into_dynamic()
+			.map_err(TilesetError::ImageEncodeError)?
+			.write_to(&mut Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
+			.map_err(|err| TilesetError::ImageEncodeError(err.into()))?;
+
+		Ok(TilesetPackage {
+			id: self.id,
+			name: self.name.clone(),
+			tiles: self.tiles.clone(),
+			tile_size: self.tile_size.to_array(),
+			tile_ids: self.tile_ids.clone(),
+			tile_names: self.tile_names.clone(),
+			tile_indices: self.tile_indices.clone(),
+			empty_tile: self.empty_tile,
+			atlas: bytes,
+			atlas_size: atlas.size.to_array(),
+			texture_rects: atlas
+				.textures
+				.iter()
+				.map(|rect| [rect.min.x, rect.min.y, rect.max.x, rect.max.y])
+				.collect(),
+		})
+	}
+
+	/// Rebuilds a tileset from the given [`TilesetPackage`]
+	///
+	/// The atlas texture and `TextureAtlas` are added to their respective assets.
+	///
+	/// Since the original tile images are not part of the package, the resulting tileset
+	/// will not contain any tile handles (i.e. [`get_tile_handle`](Self::get_tile_handle)
+	/// will always return `None`).
+	///
+	/// # Arguments
+	///
+	/// * `package`: The package to rebuild from
+	/// * `images`: The world's `Image` assets
+	/// * `atlases`: The world's `TextureAtlas` assets
+	///
+	/// returns: Result<Tileset, TilesetError>
+	///
+	pub fn from_package(
+		package: TilesetPackage,
+		images: &mut Assets<Image>,
+		atlases: &mut Assets<TextureAtlas>,
+	) -> Result<Self, TilesetError> {
+		let image = Image::from_buffer(
+			&package.atlas,
+			ImageType::Extension("png"),
+			CompressedImageFormats::NONE,
+			true,
+		)
+		.map_err(TilesetError::ImageError)?;
+		let texture = images.add(image);
+
+		let size = Vec2::from(package.atlas_size);
+		let mut atlas = TextureAtlas::new_empty(texture.clone(), size);
+		for [min_x, min_y, max_x, max_y] in package.texture_rects {
+			atlas.add_texture(Rect::new(min_x, min_y, max_x, max_y));
+		}
+		let atlas = atlases.add(atlas);
+
+		Ok(Self {
+			id: package.id,
+			name: package.name,
+			tiles: package.tiles,
+			size,
+			tile_size: Vec2::from(package.tile_size),
+			tile_ids: package.tile_ids,
+			tile_names: package.tile_names,
+			tile_handles: HashMap::new(),
+			tile_indices: package.tile_indices,
+			empty_tile: package.empty_tile,
+			atlas,
+			texture,
+		})
+	}
+}
@@ -1,7 +1,8 @@
-use crate::prelude::{Tileset, TilesetId};
-use bevy::asset::{Assets, Handle};
+use crate::prelude::{TileId, TileIndex, Tileset, TilesetId};
+use bevy::asset::{AssetServer, Assets, Handle};
 use bevy::ecs::system::SystemParam;
-use bevy::prelude::{Query, Res, Resource};
+use bevy::prelude::{Query, Res, Resource, TextureAtlas, Vec2};
+use bevy_tileset_tiles::prelude::TileData;
 use std::collections::HashMap;
 use std::ops::Deref;
 
@@ -9,18 +10,43 @@ use std::ops::Deref;
 pub struct Tilesets<'w, 's> {
 	tileset_map: Res<'w, TilesetMap>,
 	tilesets: Res<'w, Assets<Tileset>>,
+	tile_aliases: Res<'w, TileAliases>,
 
 	/// This field only exists so we can add the `'s` lifetime without Rust freaking out
 	#[allow(dead_code)]
 	phantom_query: Query<'w, 's, ()>,
 }
 
+/// A resource that maps loaded tilesets by their name and ID
+///
+/// Tilesets are registered and deregistered automatically as they are loaded and unloaded,
+/// and are accessed using the [`Tilesets`] system param.
 #[derive(Resource, Default)]
 pub struct TilesetMap {
 	name_to_id: HashMap<String, TilesetId>,
 	id_to_handle: HashMap<TilesetId, Handle<Tileset>>,
 	handle_to_id: HashMap<Handle<Tileset>, TilesetId>,
 	id_to_name: HashMap<TilesetId, String>,
+	/// Whether registered tilesets are kept loaded by this map
+	strong_handles: bool,
+}
+
+/// A resource that maps logical tile names to specific tiles
+///
+/// This allows gameplay code to refer to a tile without knowing which tileset provides it
+/// or what that tileset calls it. Aliases can be resolved using [`Tilesets::resolve`].
+#[derive(Resource, Default)]
+pub struct TileAliases {
+	aliases: HashMap<String, TileId>,
+}
+
+/// A resource that keeps track of tilesets loaded by their path
+///
+/// Tilesets are unloaded once all their strong handles are dropped. This resource holds onto
+/// those handles so that a tileset stays loaded without needing to store its handle elsewhere.
+#[derive(Resource, Default)]
+pub struct TilesetHandles {
+	path_to_handle: HashMap<String, Handle<Tileset>>,
 }
 
 impl<'w, 's> Deref for Tilesets<'w, 's> {
@@ -56,6 +82,24 @@ impl<'w, 's> Tilesets<'w, 's> {
 		self.get_by_id(id)
 	}
 
+	/// Get the tileset that owns the given `TextureAtlas`
+	///
+	/// This is useful for going from a rendered sprite back to the tileset (and tile data) behind it.
+	/// Since this checks every loaded tileset, prefer [`get_by_id`](Self::get_by_id) when the
+	/// tileset ID is known.
+	///
+	/// # Arguments
+	///
+	/// * `atlas`: The handle to the atlas
+	///
+	/// returns: Option<&Tileset>
+	pub fn find_by_atlas(&self, atlas: &Handle<TextureAtlas>) -> Option<&Tileset> {
+		self.tilesets
+			.iter()
+			.map(|(.., tileset)| tileset)
+			.find(|tileset| tileset.atlas() == atlas)
+	}
+
 	/// Checks if a tileset with the given ID exists
 	///
 	/// # Arguments
@@ -72,6 +116,71 @@ impl<'w, 's> Tilesets<'w, 's> {
 		}
 	}
 
+	/// Get the tile size of the tileset with the given ID
+	///
+	/// # Arguments
+	///
+	/// * `id`: The tileset ID
+	///
+	/// returns: Option<Vec2>
+	pub fn tile_size(&self, id: &TilesetId) -> Option<Vec2> {
+		Some(self.get_by_id(id)?.tile_size())
+	}
+
+	/// Get the size of the atlas texture of the tileset with the given ID
+	///
+	/// # Arguments
+	///
+	/// * `id`: The tileset ID
+	///
+	/// returns: Option<Vec2>
+	pub fn atlas_size(&self, id: &TilesetId) -> Option<Vec2> {
+		Some(self.get_by_id(id)?.size())
+	}
+
+	/// Get the data of a tile by its ID
+	///
+	/// This looks up the tile's tileset using the ID's `tileset_id`.
+	///
+	/// # Arguments
+	///
+	/// * `id`: The tile ID
+	///
+	/// returns: Option<&TileData>
+	pub fn get_tile_data(&self, id: &TileId) -> Option<&TileData> {
+		self.get_by_id(&id.tileset_id)?
+			.get_tile_data_by_id(&id.group_id)
+	}
+
+	/// Get the [`TileIndex`] of a tile by its ID
+	///
+	/// This looks up the tile's tileset using the ID's `tileset_id`.
+	///
+	/// # Arguments
+	///
+	/// * `id`: The tile ID
+	///
+	/// returns: Option<TileIndex>
+	pub fn get_tile_index(&self, id: &TileId) -> Option<TileIndex> {
+		self.get_by_id(&id.tileset_id)?.get_tile_index_by_id(*id)
+	}
+
+	/// Resolve a logical tile name registered in [`TileAliases`]
+	///
+	/// This only returns the tile if its tileset is currently loaded and contains the tile.
+	///
+	/// # Arguments
+	///
+	/// * `logical`: The logical name of the tile
+	///
+	/// returns: Option<TileId>
+	pub fn resolve(&self, logical: &str) -> Option<TileId> {
+		let id = self.tile_aliases.get(logical)?;
+		let tileset = self.get_by_id(&id.tileset_id)?;
+		tileset.get_tile_data_by_id(&id.group_id)?;
+		Some(*id)
+	}
+
 	/// Checks if a tileset with the given name exists
 	///
 	/// # Arguments
@@ -89,13 +198,164 @@ impl<'w, 's> Tilesets<'w, 's> {
 	}
 }
 
+impl TileAliases {
+	/// Register a logical name for the given tile
+	///
+	/// # Arguments
+	///
+	/// * `logical`: The logical name of the tile
+	/// * `id`: The ID of the tile
+	///
+	/// returns: Option<TileId>
+	///
+	/// Returns the previous tile registered with this name (if any).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn register_aliases(mut aliases: ResMut<TileAliases>, tilesets: Tilesets) {
+	/// 	let tileset = tilesets.get_by_name("My Tileset").unwrap();
+	/// 	let group_id = tileset.get_tile_group_id("grass_01").unwrap();
+	/// 	aliases.register_alias("grass", TileId::new(*group_id, *tileset.id()));
+	/// }
+	/// ```
+	pub fn register_alias(&mut self, logical: &str, id: TileId) -> Option<TileId> {
+		self.aliases.insert(logical.to_string(), id)
+	}
+
+	/// Remove the given logical name
+	///
+	/// # Arguments
+	///
+	/// * `logical`: The logical name of the tile
+	///
+	/// returns: Option<TileId>
+	pub fn remove_alias(&mut self, logical: &str) -> Option<TileId> {
+		self.aliases.remove(logical)
+	}
+
+	/// Get the tile registered with the given logical name
+	///
+	/// Unlike [`Tilesets::resolve`], this does not check that the tile actually exists.
+	///
+	/// # Arguments
+	///
+	/// * `logical`: The logical name of the tile
+	///
+	/// returns: Option<&TileId>
+	pub fn get(&self, logical: &str) -> Option<&TileId> {
+		self.aliases.get(logical)
+	}
+}
+
+impl TilesetHandles {
+	/// Get the handle to the tileset at the given path, loading it if it isn't already tracked
+	///
+	/// The returned handle is kept by this resource, ensuring the tileset is not unloaded.
+	///
+	/// # Arguments
+	///
+	/// * `path`: The asset path to the tileset's configuration file
+	/// * `asset_server`: The asset server used to load the tileset
+	///
+	/// returns: Handle<Tileset>
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn load_tileset(mut handles: ResMut<TilesetHandles>, asset_server: Res<AssetServer>) {
+	/// 	let handle = handles.get_or_load("tilesets/my_tileset.ron", &asset_server);
+	/// }
+	/// ```
+	pub fn get_or_load(&mut self, path: &str, asset_server: &AssetServer) -> Handle<Tileset> {
+		self.path_to_handle
+			.entry(path.to_string())
+			.or_insert_with(|| asset_server.load(path))
+			.clone()
+	}
+
+	/// Get the handle to the tileset at the given path (if tracked)
+	///
+	/// # Arguments
+	///
+	/// * `path`: The asset path to the tileset's configuration file
+	///
+	/// returns: Option<&Handle<Tileset>>
+	pub fn get(&self, path: &str) -> Option<&Handle<Tileset>> {
+		self.path_to_handle.get(path)
+	}
+
+	/// Stops tracking the tileset at the given path, returning its handle
+	///
+	/// If no other strong handles exist, the tileset will be unloaded.
+	///
+	/// # Arguments
+	///
+	/// * `path`: The asset path to the tileset's configuration file
+	///
+	/// returns: Option<Handle<Tileset>>
+	pub fn remove(&mut self, path: &str) -> Option<Handle<Tileset>> {
+		self.path_to_handle.remove(path)
+	}
+}
+
 impl TilesetMap {
+	/// Sets whether registered tilesets should be kept loaded by this map
+	///
+	/// By default, this map only stores weak handles, so a tileset is unloaded (and deregistered)
+	/// once all of its strong handles are dropped. Enabling this keeps every tileset registered
+	/// from then on loaded until it is [released](Self::release).
+	///
+	/// # Arguments
+	///
+	/// * `strong_handles`: Whether to store strong handles
+	///
+	/// returns: ()
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn setup(mut map: ResMut<TilesetMap>, asset_server: Res<AssetServer>) {
+	/// 	map.set_strong_handles(true);
+	/// 	// The handle may be dropped since the map will keep the tileset loaded
+	/// 	let _ = asset_server.load::<Tileset, _>("tilesets/my_tileset.ron");
+	/// }
+	/// ```
+	pub fn set_strong_handles(&mut self, strong_handles: bool) {
+		self.strong_handles = strong_handles;
+	}
+
+	/// Stops keeping the tileset with the given ID loaded
+	///
+	/// The tileset stays registered until it is actually unloaded, which only happens once
+	/// no other strong handles to it exist.
+	///
+	/// # Arguments
+	///
+	/// * `id`: The ID of the tileset
+	///
+	/// returns: ()
+	pub fn release(&mut self, id: &TilesetId) {
+		if let Some(handle) = self.id_to_handle.get_mut(id) {
+			*handle = handle.clone_weak();
+		}
+	}
+
 	/// Register a tileset for easy lookup in the [Tilesets] system param.
 	///
 	/// # Arguments
 	///
 	/// * `tileset`: The tileset to register
-	/// * `handle`: The handle to the tileset
+	/// * `handle`: A strong handle to the tileset
 	///
 	/// returns: ()
 	pub(crate) fn register_tileset(&mut self, tileset: &Tileset, handle: &Handle<Tileset>) {
@@ -104,7 +364,12 @@ impl TilesetMap {
 			.insert(*tileset.id(), tileset.name().to_string());
 		self.name_to_id
 			.insert(tileset.name().to_string(), *tileset.id());
-		self.id_to_handle.insert(*tileset.id(), handle.clone_weak());
+		let handle = if self.strong_handles {
+			handle.clone()
+		} else {
+			handle.clone_weak()
+		};
+		self.id_to_handle.insert(*tileset.id(), handle);
 	}
 
 	/// Deregisters a tileset so it is no longer tracked
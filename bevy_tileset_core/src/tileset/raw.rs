@@ -18,6 +18,7 @@ impl RawTileset {
 			tile_names: self.tile_names,
 			tile_handles: self.tile_handles,
 			tile_indices: self.tile_indices,
+			empty_tile: self.empty_tile,
 			atlas,
 			texture,
 		}
@@ -0,0 +1,114 @@
+//! The random number generators used when selecting variants
+//!
+//! Rather than `rand::thread_rng`, which periodically reseeds itself from the OS, these are
+//! lightweight generators seeded only once. This keeps variant selection cheap and avoids
+//! repeatedly reaching for OS entropy, which is limited on targets like WASM.
+
+use std::cell::RefCell;
+
+use bevy::prelude::Resource;
+use rand::rngs::{OsRng, SmallRng};
+use rand::{Error, RngCore, SeedableRng};
+
+/// The seed used when the platform is unable to provide any entropy
+const FALLBACK_SEED: u64 = 0x7115_E75E_ED00_0001;
+
+thread_local! {
+	static DEFAULT_RNG: RefCell<SmallRng> = RefCell::new(seed_rng());
+}
+
+/// A handle to the RNG used by methods that don't take one explicitly (i.e. `select_variant`)
+///
+/// Each thread has its own generator, which is seeded the first time it is used.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct DefaultRng;
+
+/// Get a handle to the default RNG of the current thread
+pub(crate) fn default_rng() -> DefaultRng {
+	DefaultRng
+}
+
+/// A resource containing an RNG that can be passed to any of the `*_with_rng` methods
+///
+/// This is added automatically by the [`TilesetPlugin`](crate::prelude::TilesetPlugin) and is
+/// seeded from the OS once. Replace it with [`TilesetRng::from_seed`] to make variant selection
+/// reproducible (i.e. for replays or tests).
+///
+/// # Examples
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tileset_core::prelude::*;
+/// # use bevy_tileset_tiles::prelude::*;
+///
+/// fn pick_grass(tilesets: Tilesets, mut rng: ResMut<TilesetRng>) {
+/// 	let tileset = tilesets.get_by_name("My Tileset").unwrap();
+/// 	let data = tileset.get_tile_data("Grass").unwrap();
+/// 	if let TileType::Variant(variants) = data.tile() {
+/// 		let variant = Tileset::select_variant_with_rng(variants, &mut *rng);
+/// 	}
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct TilesetRng(SmallRng);
+
+impl TilesetRng {
+	/// Create an RNG with the given seed
+	///
+	/// # Arguments
+	///
+	/// * `seed`: The seed of the RNG
+	///
+	/// returns: TilesetRng
+	///
+	pub fn from_seed(seed: u64) -> Self {
+		Self(SmallRng::seed_from_u64(seed))
+	}
+}
+
+impl Default for TilesetRng {
+	fn default() -> Self {
+		Self(seed_rng())
+	}
+}
+
+impl RngCore for TilesetRng {
+	fn next_u32(&mut self) -> u32 {
+		self.0.next_u32()
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		self.0.next_u64()
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		self.0.fill_bytes(dest)
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+		self.0.try_fill_bytes(dest)
+	}
+}
+
+impl RngCore for DefaultRng {
+	fn next_u32(&mut self) -> u32 {
+		DEFAULT_RNG.with(|rng| rng.borrow_mut().next_u32())
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		DEFAULT_RNG.with(|rng| rng.borrow_mut().next_u64())
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		DEFAULT_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest))
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+		DEFAULT_RNG.with(|rng| rng.borrow_mut().try_fill_bytes(dest))
+	}
+}
+
+/// Seeds a new RNG from the OS, falling back to a fixed seed if no entropy is available
+fn seed_rng() -> SmallRng {
+	SmallRng::from_rng(OsRng).unwrap_or_else(|_| SmallRng::seed_from_u64(FALLBACK_SEED))
+}
@@ -0,0 +1,94 @@
+//! Helpers for rendering tiles with plain Bevy sprites (without a dedicated tilemap crate)
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::{Commands, Entity, Quat, SpriteSheetBundle, TextureAtlasSprite, Transform};
+
+use crate::prelude::{TileIndex, Tileset};
+use bevy_tileset_tiles::prelude::*;
+
+impl Tileset {
+	/// Creates a `TextureAtlasSprite` for the given tile index
+	///
+	/// For [`TileIndex::Animated`] tiles, the first frame is used.
+	///
+	/// # Arguments
+	///
+	/// * `index`: The tile's index
+	/// * `flip`: The orientation of the tile
+	///
+	/// returns: TextureAtlasSprite
+	///
+	pub fn sprite(&self, index: &TileIndex, flip: TileFlip) -> TextureAtlasSprite {
+		let mut sprite = TextureAtlasSprite::new(*index.base_index());
+		// The anti-diagonal flip is a horizontal flip followed by a 90° rotation (see `sprite_bundle`)
+		sprite.flip_x = flip.x ^ flip.d;
+		sprite.flip_y = flip.y;
+		sprite
+	}
+
+	/// Creates a `SpriteSheetBundle` that renders the given tile index using this tileset's atlas
+	///
+	/// # Arguments
+	///
+	/// * `index`: The tile's index
+	/// * `flip`: The orientation of the tile
+	/// * `transform`: The transform of the sprite
+	///
+	/// returns: SpriteSheetBundle
+	///
+	pub fn sprite_bundle(
+		&self,
+		index: &TileIndex,
+		flip: TileFlip,
+		mut transform: Transform,
+	) -> SpriteSheetBundle {
+		if flip.d {
+			transform.rotate(Quat::from_rotation_z(FRAC_PI_2));
+		}
+
+		SpriteSheetBundle {
+			sprite: self.sprite(index, flip),
+			texture_atlas: self.atlas.clone(),
+			transform,
+			..Default::default()
+		}
+	}
+
+	/// Spawns a sprite for the tile with the given name
+	///
+	/// The tile is selected just like in [`select_tile`](Self::select_tile) and is given its
+	/// default orientation. Since plain sprites are not animated, animated tiles only display
+	/// their first frame.
+	///
+	/// # Arguments
+	///
+	/// * `commands`: The world's `Commands`
+	/// * `name`: The name of the tile
+	/// * `transform`: The transform of the sprite
+	///
+	/// returns: Option<Entity>
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy::prelude::*;
+	/// # use bevy_tileset_core::prelude::*;
+	///
+	/// fn spawn_grass(tilesets: Tilesets, mut commands: Commands) {
+	/// 	if let Some(tileset) = tilesets.get_by_name("My Tileset") {
+	/// 		tileset.spawn_tile(&mut commands, "Grass", Transform::from_xyz(16.0, 0.0, 0.0));
+	/// 	}
+	/// }
+	/// ```
+	pub fn spawn_tile(
+		&self,
+		commands: &mut Commands,
+		name: &str,
+		transform: Transform,
+	) -> Option<Entity> {
+		let (index, data) = self.select_tile(name)?;
+		let bundle = self.sprite_bundle(&index, data.flip(), transform);
+		Some(commands.spawn(bundle).id())
+	}
+}
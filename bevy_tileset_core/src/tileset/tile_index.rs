@@ -5,10 +5,15 @@ use bevy_tileset_tiles::prelude::*;
 pub enum TileIndex {
 	/// Index for a standard tile
 	Standard(usize),
-	/// Indexes for an animated tile.
-	///
-	/// Takes the form (start, end, speed)
-	Animated(usize, usize, f32),
+	/// Indexes for an animated tile
+	Animated {
+		/// The index of the first frame (inclusive)
+		start: usize,
+		/// The index of the last frame (inclusive)
+		end: usize,
+		/// The speed of the animation (in frames per second)
+		speed: f32,
+	},
 }
 
 impl TileIndex {
@@ -20,20 +25,43 @@ impl TileIndex {
 	pub fn base_index(&self) -> &usize {
 		match self {
 			Self::Standard(idx) => idx,
-			Self::Animated(idx, ..) => idx,
+			Self::Animated { start, .. } => start,
+		}
+	}
+
+	/// Checks if this is the index of an animated tile
+	pub fn is_animated(&self) -> bool {
+		matches!(self, Self::Animated { .. })
+	}
+
+	/// Gets the number of frames this index spans
+	///
+	/// This is always 1 for [`TileIndex::Standard`].
+	pub fn frame_count(&self) -> usize {
+		match self {
+			Self::Standard(..) => 1,
+			Self::Animated { start, end, .. } => end - start + 1,
 		}
 	}
 }
 
 impl From<AnimatedTileData> for TileIndex {
 	fn from(data: AnimatedTileData) -> Self {
-		TileIndex::Animated(data.start(), data.end(), data.speed())
+		TileIndex::Animated {
+			start: data.start(),
+			end: data.end(),
+			speed: data.speed(),
+		}
 	}
 }
 
 impl From<&AnimatedTileData> for TileIndex {
 	fn from(data: &AnimatedTileData) -> Self {
-		TileIndex::Animated(data.start(), data.end(), data.speed())
+		TileIndex::Animated {
+			start: data.start(),
+			end: data.end(),
+			speed: data.speed(),
+		}
 	}
 }
 
@@ -1,4 +1,7 @@
+use std::ops::Range;
+
 use bevy_asset::Handle;
+use bevy_math::UVec2;
 use bevy_render::texture::Image;
 use serde::{Deserialize, Serialize};
 
@@ -6,8 +9,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Made to be easily used with [`bevy_ecs_tilemap::GPUAnimated`] component
 #[derive(Debug, Copy, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct AnimatedTileData {
-	/// The speed of the animation
+	/// The speed of the animation (in frames per second)
 	speed: f32,
 	/// The start index of the animation (inclusive)
 	start: usize,
@@ -24,6 +28,24 @@ pub struct AnimatedTileHandle {
 	///
 	/// Each frame is a registered [`Handle`]
 	pub frames: Vec<Handle<Image>>,
+	/// The spritesheet to slice additional frames from (if any)
+	pub sheet: Option<AnimatedSheetHandle>,
+	/// The atlas indices of textures already added to the tileset to use as frames (if any)
+	///
+	/// This allows an animation to reuse textures without adding them to the atlas again, such
+	/// as the cells of a tileset's sheet. It cannot be combined with `frames` or `sheet`.
+	pub indices: Option<Range<usize>>,
+}
+
+/// A structure defining a spritesheet containing the frames of an animation
+#[derive(Debug, Clone)]
+pub struct AnimatedSheetHandle {
+	/// The handle to the spritesheet texture
+	pub handle: Handle<Image>,
+	/// The size of a single frame (in pixels)
+	pub frame_size: UVec2,
+	/// The number of frames in the spritesheet
+	pub frames: usize,
 }
 
 /// A structure defining an animated tile
@@ -33,9 +55,22 @@ pub struct AnimatedTileHandle {
 pub struct AnimatedTileDef {
 	/// The speed of the animation
 	///
+	/// This is passed directly to `GPUAnimated`, which advances the animation by `speed` frames
+	/// every second. In other words, this is the same as the animation's frames per second.
+	///
+	/// This is ignored if `fps` is given.
+	///
 	/// Default: 1.0
 	#[serde(default = "default_speed")]
 	pub speed: f32,
+	/// The number of frames shown per second
+	///
+	/// This is an alternative to `speed` that makes the unit explicit. If given, it
+	/// takes precedence over `speed`.
+	///
+	/// Default: None
+	#[serde(default)]
+	pub fps: Option<f32>,
 	/// The frames of the animation
 	///
 	/// Each entry is a path to a texture relative to the configuration file
@@ -55,6 +90,90 @@ pub struct AnimatedTileDef {
 	/// ```
 	#[serde(default)]
 	pub frames: Vec<String>,
+	/// A spritesheet to slice frames from
+	///
+	/// This allows an animation to be defined using a single texture rather than one texture
+	/// per frame. If both `frames` and `sheet` are given, the sliced frames are placed after
+	/// the ones in `frames`.
+	///
+	/// # Examples
+	///
+	/// ```ron
+	/// (
+	/// 	// ...
+	/// 	sheet: Some((
+	/// 		path: "strip.png",
+	/// 		frame_size: (16, 16),
+	/// 		frames: 4,
+	/// 	)),
+	/// 	// ...
+	/// )
+	/// ```
+	#[serde(default)]
+	pub sheet: Option<AnimatedSheetDef>,
+	/// The cells of the tileset's sheet to use as frames
+	///
+	/// This allows an animation to use the cells that the tileset's `sheet` already placed in
+	/// its atlas, rather than listing (or slicing) each frame again. Cells are counted
+	/// left-to-right, top-to-bottom, starting at 0. This cannot be combined with `frames` or `sheet`.
+	///
+	/// # Examples
+	///
+	/// ```ron
+	/// (
+	/// 	// ...
+	/// 	range: Some((from: 8, to: 11)),
+	/// 	// ...
+	/// )
+	/// ```
+	///
+	/// Default: None
+	#[serde(default)]
+	pub range: Option<FrameRange>,
+}
+
+/// A structure defining a spritesheet containing the frames of an animation
+///
+/// Frames are read left-to-right, top-to-bottom, starting at the top-left corner
+/// of the spritesheet.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AnimatedSheetDef {
+	/// The path to the spritesheet texture relative to the configuration file
+	pub path: String,
+	/// The size of a single frame (in pixels)
+	pub frame_size: UVec2,
+	/// The number of frames in the spritesheet
+	pub frames: usize,
+}
+
+/// An inclusive range of cells within a spritesheet
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FrameRange {
+	/// The index of the first cell (inclusive)
+	pub from: usize,
+	/// The index of the last cell (inclusive)
+	pub to: usize,
+}
+
+impl AnimatedTileDef {
+	/// Gets the speed of the animation, preferring `fps` over `speed` if it was given
+	pub fn frame_rate(&self) -> f32 {
+		self.fps.unwrap_or(self.speed)
+	}
+
+	/// Gets the cells of the tileset's sheet to use as frames (if any)
+	pub fn frame_range(&self) -> Option<Range<usize>> {
+		self.range.map(|range| range.from..range.to + 1)
+	}
+}
+
+impl AnimatedTileHandle {
+	/// Iterate over all texture handles used by this animation
+	pub fn iter_handles(&self) -> impl Iterator<Item = &Handle<Image>> {
+		self.frames
+			.iter()
+			.chain(self.sheet.iter().map(|sheet| &sheet.handle))
+	}
 }
 
 impl AnimatedTileData {
@@ -72,14 +191,17 @@ impl AnimatedTileData {
 		self.end
 	}
 
-	/// Gets the animation speed
+	/// Gets the animation speed (in frames per second)
 	pub fn speed(&self) -> f32 {
 		self.speed
 	}
 
 	/// Gets the number of frames in this animation
+	///
+	/// Since both the start and end indices are inclusive, an animation whose start and end
+	/// are the same index still has a single frame.
 	pub fn frame_count(&self) -> usize {
-		self.end - self.start
+		self.end - self.start + 1
 	}
 }
 
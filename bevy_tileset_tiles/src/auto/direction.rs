@@ -0,0 +1,47 @@
+use bevy_math::IVec2;
+use serde::{Deserialize, Serialize};
+
+/// The eight directions surrounding a tile
+///
+/// This can be used to index into an [`AutoTileRule`](crate::prelude::AutoTileRule).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum Direction {
+	North,
+	NorthEast,
+	East,
+	SouthEast,
+	South,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+impl Direction {
+	/// All directions in clockwise order, starting with [`Direction::North`]
+	pub const ALL: [Direction; 8] = [
+		Direction::North,
+		Direction::NorthEast,
+		Direction::East,
+		Direction::SouthEast,
+		Direction::South,
+		Direction::SouthWest,
+		Direction::West,
+		Direction::NorthWest,
+	];
+
+	/// Gets the offset to the neighboring tile in this direction
+	///
+	/// North is in the positive y direction and east is in the positive x direction.
+	pub fn offset(&self) -> IVec2 {
+		match self {
+			Self::North => IVec2::new(0, 1),
+			Self::NorthEast => IVec2::new(1, 1),
+			Self::East => IVec2::new(1, 0),
+			Self::SouthEast => IVec2::new(1, -1),
+			Self::South => IVec2::new(0, -1),
+			Self::SouthWest => IVec2::new(-1, -1),
+			Self::West => IVec2::new(-1, 0),
+			Self::NorthWest => IVec2::new(-1, 1),
+		}
+	}
+}
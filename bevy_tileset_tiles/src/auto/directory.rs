@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use bevy_render::texture::ImageFormat;
+
+use crate::error::TileError;
+use crate::prelude::{AutoTileDef, AutoTileRule, SimpleTileDefType, VariantTileDef};
+
+/// The naming scheme used to convert a file suffix into an [`AutoTileRule`]
+///
+/// The suffix is the part of a file's name (without its extension) following the
+/// base name and an underscore. For example, the suffix of `wall_n_e.png` with a
+/// base name of `wall` is `n_e`.
+///
+/// A file named exactly after the base name (i.e. `wall.png`) is always given the
+/// default rule.
+#[derive(Debug, Copy, Clone)]
+pub enum SuffixScheme {
+	/// The suffix is an underscore-separated list of compass directions
+	/// (`n`, `ne`, `e`, `se`, `s`, `sw`, `w`, `nw`)
+	///
+	/// Each listed direction must contain a neighbor, while unlisted directions are ignored.
+	/// For example, `wall_n_e.png` matches tiles with a neighbor to the north and east.
+	Compass,
+	/// The suffix is parsed using the given function
+	///
+	/// Returning `None` marks the suffix as unknown.
+	Custom(fn(&str) -> Option<AutoTileRule>),
+}
+
+impl SuffixScheme {
+	/// Parse the given suffix into a rule
+	///
+	/// # Arguments
+	///
+	/// * `suffix`: The suffix to parse
+	///
+	/// returns: Option<AutoTileRule>
+	///
+	pub fn parse(&self, suffix: &str) -> Option<AutoTileRule> {
+		match self {
+			Self::Compass => {
+				suffix
+					.split('_')
+					.try_fold(AutoTileRule::default(), |mut rule, direction| {
+						let dir = match direction {
+							"n" => &mut rule.north,
+							"ne" => &mut rule.north_east,
+							"e" => &mut rule.east,
+							"se" => &mut rule.south_east,
+							"s" => &mut rule.south,
+							"sw" => &mut rule.south_west,
+							"w" => &mut rule.west,
+							"nw" => &mut rule.north_west,
+							_ => return None,
+						};
+						*dir = Some(true);
+						Some(rule)
+					})
+			},
+			Self::Custom(parse) => parse(suffix),
+		}
+	}
+}
+
+impl AutoTileDef {
+	/// Create a set of auto tiles from a directory of suffixed image files
+	///
+	/// Each file named after `base_name` becomes its own auto tile, with a rule parsed from its
+	/// suffix (see [`SuffixScheme`]). Files not named after `base_name`, as well as any files that
+	/// aren't a supported image format (such as `.DS_Store` or `README.md`), are skipped. The auto
+	/// tiles are sorted so that the most restrictive rules come first.
+	///
+	/// The texture path of each auto tile is `dir` joined with the file name, so `dir` should be
+	/// the same path that would be written in the tile's definition file.
+	///
+	/// # Arguments
+	///
+	/// * `dir`: The directory to scan
+	/// * `base_name`: The name shared by all files in this auto tile
+	/// * `naming`: The scheme used to parse file suffixes
+	///
+	/// returns: Result<Vec<AutoTileDef>, TileError>
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use bevy_tileset_tiles::prelude::*;
+	///
+	/// // Contains: wall.png, wall_n.png, wall_n_e.png, ...
+	/// let autos = AutoTileDef::from_directory("textures/wall", "wall", SuffixScheme::Compass).unwrap();
+	/// let tile = TileDef {
+	/// 	name: String::from("Wall"),
+	/// 	tile: TileDefType::Auto(autos),
+	/// 	flip: TileFlip::default(),
+	/// 	sfx: None,
+	/// };
+	/// ```
+	pub fn from_directory<P: AsRef<Path>>(
+		dir: P,
+		base_name: &str,
+		naming: SuffixScheme,
+	) -> Result<Vec<Self>, TileError> {
+		let dir = dir.as_ref();
+		let mut autos = Vec::new();
+		let mut unknown = Vec::new();
+
+		for entry in std::fs::read_dir(dir).map_err(TileError::IoError)? {
+			let path = entry.map_err(TileError::IoError)?.path();
+			if !path.is_file() || !is_image(&path) {
+				continue;
+			}
+
+			let (stem, file_name) = match (path.file_stem(), path.file_name()) {
+				(Some(stem), Some(file_name)) => {
+					(stem.to_string_lossy(), file_name.to_string_lossy())
+				},
+				_ => continue,
+			};
+
+			let rule = if stem == base_name {
+				AutoTileRule::default()
+			} else if let Some(suffix) = stem
+				.strip_prefix(base_name)
+				.and_then(|rest| rest.strip_prefix('_'))
+			{
+				match naming.parse(suffix) {
+					Some(rule) => rule,
+					None => {
+						unknown.push(suffix.to_string());
+						continue;
+					},
+				}
+			} else {
+				continue;
+			};
+
+			let texture_path = dir.join(file_name.as_ref()).to_string_lossy().to_string();
+			autos.push((
+				file_name.to_string(),
+				AutoTileDef {
+					rule,
+					variants: vec![VariantTileDef {
+						weight: 1.0,
+						tile: SimpleTileDefType::Standard(texture_path),
+					}],
+					fallback: false,
+					fallback_tile: None,
+					isolated: false,
+				},
+			));
+		}
+
+		if !unknown.is_empty() {
+			unknown.sort();
+			return Err(TileError::UnknownSuffixes(unknown));
+		}
+
+		// Most restrictive rules first (ties are broken by file name to keep things deterministic)
+		autos.sort_by(|(a_name, a), (b_name, b)| {
+			b.rule
+				.specified_count()
+				.cmp(&a.rule.specified_count())
+				.then_with(|| a_name.cmp(b_name))
+		});
+
+		Ok(autos.into_iter().map(|(.., auto)| auto).collect())
+	}
+}
+
+/// Checks if the given file has the extension of a supported image format
+fn is_image(path: &Path) -> bool {
+	path.extension()
+		.and_then(|ext| ext.to_str())
+		.and_then(ImageFormat::from_extension)
+		.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::PathBuf;
+
+	use crate::error::TileError;
+	use crate::prelude::*;
+
+	/// Creates a temporary directory containing empty files with the given names
+	fn create_dir(name: &str, files: &[&str]) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("bevy_tileset_tiles-{}", name));
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).unwrap();
+		for file in files {
+			std::fs::write(dir.join(file), []).unwrap();
+		}
+		dir
+	}
+
+	#[test]
+	fn should_create_from_directory() {
+		let dir = create_dir(
+			"from_directory",
+			&[
+				"wall.png",
+				"wall_n.png",
+				"wall_n_e.png",
+				"floor.png",
+				".DS_Store",
+				"README.md",
+				"wall_notes.txt",
+			],
+		);
+
+		let autos = AutoTileDef::from_directory(&dir, "wall", SuffixScheme::Compass).unwrap();
+		let rules = autos.iter().map(|auto| auto.rule).collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				AutoTileRule {
+					north: Some(true),
+					east: Some(true),
+					..Default::default()
+				},
+				AutoTileRule {
+					north: Some(true),
+					..Default::default()
+				},
+				AutoTileRule::default(),
+			],
+			rules
+		);
+
+		match &autos[0].variants[0].tile {
+			SimpleTileDefType::Standard(path) => {
+				assert_eq!(dir.join("wall_n_e.png").to_string_lossy(), path.as_str())
+			},
+			tile => panic!("expected standard tile, found {:?}", tile),
+		}
+	}
+
+	#[test]
+	fn should_list_unknown_suffixes() {
+		let dir = create_dir(
+			"unknown_suffixes",
+			&["wall_n.png", "wall_up.png", "wall_n_x.png"],
+		);
+
+		match AutoTileDef::from_directory(&dir, "wall", SuffixScheme::Compass) {
+			Err(TileError::UnknownSuffixes(suffixes)) => assert_eq!(vec!["n_x", "up"], suffixes),
+			result => panic!("expected unknown suffixes, found {:?}", result),
+		}
+	}
+}
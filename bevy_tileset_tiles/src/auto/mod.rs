@@ -1,19 +1,33 @@
+mod direction;
+mod directory;
 mod rules;
+mod validation;
 
+use crate::error::TileError;
 use crate::prelude::{VariantTileData, VariantTileDef, VariantTileHandle};
+pub use direction::Direction;
+pub use directory::SuffixScheme;
 pub use rules::AutoTileRule;
 use serde::{Deserialize, Serialize};
+pub use validation::{validate_rules, RuleIssue};
 
 /// A structure defining an auto tile
 ///
 /// An auto tile contains rules that are applied when placed, removed, or changed
 /// to itself and to its neighbors of the same type
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct AutoTileData {
 	/// The rule defining this tile
 	rule: AutoTileRule,
 	/// The underlying tile variants
 	variants: Vec<VariantTileData>,
+	/// Whether this tile is used when no rule matches
+	#[serde(default)]
+	fallback: bool,
+	/// The name of another tile in the tileset to use when no rule matches (if any)
+	#[serde(default)]
+	fallback_tile: Option<String>,
 }
 
 /// A structure defining an auto tile
@@ -23,6 +37,10 @@ pub struct AutoTileHandle {
 	pub rule: AutoTileRule,
 	/// The underlying variant handles
 	pub variants: Vec<VariantTileHandle>,
+	/// Whether this tile is used when no rule matches
+	pub fallback: bool,
+	/// The name of another tile in the tileset to use when no rule matches (if any)
+	pub fallback_tile: Option<String>,
 }
 
 /// A structure defining an auto tile
@@ -37,11 +55,97 @@ pub struct AutoTileDef {
 	/// The underlying tile variants
 	#[serde(default)]
 	pub variants: Vec<VariantTileDef>,
+	/// Whether this tile should be used when no rule matches
+	///
+	/// Without a fallback (or a [`fallback_tile`](Self::fallback_tile)), the last auto tile is
+	/// used instead. If multiple auto tiles are marked as a fallback, the first one is used.
+	///
+	/// Default: false
+	#[serde(default)]
+	pub fallback: bool,
+	/// The name of another tile in the same tileset to use when no rule matches
+	///
+	/// This only applies if no auto tile is marked as the [`fallback`](Self::fallback). It allows
+	/// an unmatched tile to show something sensible (such as the base terrain it sits on) rather
+	/// than the last auto tile. If multiple auto tiles name a fallback tile, the first one is used.
+	///
+	/// An unknown name is ignored when selecting tiles, but is reported by the tileset's `validate` method.
+	///
+	/// Default: None
+	#[serde(default)]
+	pub fallback_tile: Option<String>,
+	/// Whether this is the piece used by tiles without any neighbors
+	///
+	/// If true, `rule` is replaced by [`AutoTileRule::isolated`]. Tiles without any neighbors
+	/// will always select this piece, regardless of the order of the other rules.
+	///
+	/// Default: false
+	#[serde(default)]
+	pub isolated: bool,
+}
+
+impl AutoTileDef {
+	/// Gets the rule for this tile, taking `isolated` into account
+	pub fn effective_rule(&self) -> AutoTileRule {
+		if self.isolated {
+			AutoTileRule::isolated()
+		} else {
+			self.rule
+		}
+	}
 }
 
 impl AutoTileData {
 	pub fn new(rule: AutoTileRule, variants: Vec<VariantTileData>) -> Self {
-		AutoTileData { rule, variants }
+		AutoTileData {
+			rule,
+			variants,
+			fallback: false,
+			fallback_tile: None,
+		}
+	}
+
+	/// Sets whether this tile is used when no rule matches
+	///
+	/// # Arguments
+	///
+	/// * `fallback`: Whether this tile is the fallback
+	///
+	/// returns: AutoTileData
+	///
+	pub fn with_fallback(mut self, fallback: bool) -> Self {
+		self.fallback = fallback;
+		self
+	}
+
+	/// Sets the name of the tile used when no rule matches
+	///
+	/// # Arguments
+	///
+	/// * `fallback_tile`: The name of another tile in the same tileset
+	///
+	/// returns: AutoTileData
+	///
+	pub fn with_fallback_tile(mut self, fallback_tile: Option<String>) -> Self {
+		self.fallback_tile = fallback_tile;
+		self
+	}
+
+	/// Create a new [`AutoTileData`], ensuring it has at least one variant to select from
+	///
+	/// # Arguments
+	///
+	/// * `rule`: The rule defining this tile
+	/// * `variants`: The underlying tile variants
+	///
+	/// returns: Result<AutoTileData, TileError>
+	///
+	pub fn try_new(rule: AutoTileRule, variants: Vec<VariantTileData>) -> Result<Self, TileError> {
+		if variants.is_empty() {
+			Err(TileError::NoVariants)
+		} else {
+			Ok(Self::new(rule, variants))
+		}
 	}
 
 	/// Gets the rule associated with this auto tile
@@ -53,4 +157,14 @@ impl AutoTileData {
 	pub fn variants(&self) -> &Vec<VariantTileData> {
 		&self.variants
 	}
+
+	/// Checks if this tile is used when no rule matches
+	pub fn is_fallback(&self) -> bool {
+		self.fallback
+	}
+
+	/// Gets the name of the tile used when no rule matches (if any)
+	pub fn fallback_tile(&self) -> Option<&str> {
+		self.fallback_tile.as_deref()
+	}
 }
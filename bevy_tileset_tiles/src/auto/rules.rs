@@ -1,5 +1,9 @@
+use std::ops::{Index, IndexMut};
+
 use serde::{Deserialize, Serialize};
 
+use crate::auto::Direction;
+
 /// The rules used to define an auto tile
 ///
 /// The possible states are:
@@ -82,6 +86,146 @@ impl AutoTileRule {
 			&& Self::check_bool(self.south_west, other.south_west)
 	}
 
+	/// Gets the number of directions constrained by this rule (i.e. the directions not set to `None`)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_tiles::prelude::AutoTileRule;
+	///
+	/// let rule = AutoTileRule { north: Some(true), south: Some(false), ..Default::default() };
+	///
+	/// assert_eq!(2, rule.specified_count());
+	/// ```
+	pub fn specified_count(&self) -> usize {
+		self.iter().filter(|(.., value)| value.is_some()).count()
+	}
+
+	/// Returns true if every direction of this rule is constrained (i.e. none are set to `None`)
+	pub fn is_fully_specified(&self) -> bool {
+		self.iter().all(|(.., value)| value.is_some())
+	}
+
+	/// Checks if this rule conflicts with the given rule
+	///
+	/// Two rules conflict if there is any direction where one rule is set to `Some(true)` and the
+	/// other is set to `Some(false)`. Directions set to `None` never conflict.
+	///
+	/// # Arguments
+	///
+	/// * `other`: The other rule to check against
+	///
+	/// returns: bool
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_tiles::prelude::AutoTileRule;
+	///
+	/// let a = AutoTileRule { north: Some(true), ..Default::default() };
+	/// let b = AutoTileRule { north: Some(false), ..Default::default() };
+	/// let c = AutoTileRule { east: Some(false), ..Default::default() };
+	///
+	/// assert!(a.conflicts_with(&b));
+	/// assert!(!a.conflicts_with(&c));
+	/// ```
+	pub fn conflicts_with(&self, other: &AutoTileRule) -> bool {
+		self.to_mask()
+			.into_iter()
+			.zip(other.to_mask())
+			.any(|(lhs, rhs)| matches!((lhs, rhs), (Some(l_val), Some(r_val)) if l_val != r_val))
+	}
+
+	/// Returns a copy of this rule rotated 90° clockwise
+	///
+	/// Each direction is moved to the next direction clockwise (i.e. N → E → S → W and
+	/// NE → SE → SW → NW). This allows a single rule (and its texture) to be reused for
+	/// all four orientations of a tile.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_tiles::prelude::AutoTileRule;
+	///
+	/// let rule = AutoTileRule { north: Some(true), north_east: Some(false), ..Default::default() };
+	/// let rotated = AutoTileRule { east: Some(true), south_east: Some(false), ..Default::default() };
+	///
+	/// assert_eq!(rotated, rule.rotated_cw());
+	/// ```
+	pub fn rotated_cw(&self) -> Self {
+		Self {
+			north: self.west,
+			east: self.north,
+			south: self.east,
+			west: self.south,
+			north_east: self.north_west,
+			south_east: self.north_east,
+			south_west: self.south_east,
+			north_west: self.south_west,
+		}
+	}
+
+	/// Iterate over every direction of this rule along with its value
+	///
+	/// Directions are returned in clockwise order, starting with [`Direction::North`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_tiles::prelude::{AutoTileRule, Direction};
+	///
+	/// let rule = AutoTileRule { north: Some(true), ..Default::default() };
+	/// let required: Vec<Direction> = rule
+	/// 	.iter()
+	/// 	.filter(|(.., value)| *value == Some(true))
+	/// 	.map(|(direction, ..)| direction)
+	/// 	.collect();
+	///
+	/// assert_eq!(vec![Direction::North], required);
+	/// ```
+	pub fn iter(&self) -> impl Iterator<Item = (Direction, Option<bool>)> + '_ {
+		Direction::ALL
+			.into_iter()
+			.map(|direction| (direction, self[direction]))
+	}
+
+	/// Creates a fully specified rule from the given neighbor mask
+	///
+	/// The mask is ordered clockwise, starting with north: `[N, NE, E, SE, S, SW, W, NW]`
+	/// (the same order as [`Direction::ALL`]). A value of `true` means a neighbor is present.
+	///
+	/// # Arguments
+	///
+	/// * `mask`: The presence of each neighbor
+	///
+	/// returns: AutoTileRule
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use bevy_tileset_tiles::prelude::AutoTileRule;
+	///
+	/// let rule = AutoTileRule::from_mask([true, false, false, false, false, false, false, false]);
+	///
+	/// assert_eq!(Some(true), rule.north);
+	/// assert_eq!(Some(false), rule.south);
+	/// ```
+	pub fn from_mask(mask: [bool; 8]) -> Self {
+		let mut rule = Self::default();
+		for (direction, value) in Direction::ALL.into_iter().zip(mask) {
+			rule[direction] = Some(value);
+		}
+		rule
+	}
+
+	/// Gets the value of every direction of this rule as a mask
+	///
+	/// The mask is ordered clockwise, starting with north: `[N, NE, E, SE, S, SW, W, NW]`
+	/// (the same order as [`Direction::ALL`]).
+	pub fn to_mask(&self) -> [Option<bool>; 8] {
+		Direction::ALL.map(|direction| self[direction])
+	}
+
 	/// Returns a default rule where all directions are set to `false`
 	pub fn default_false() -> Self {
 		Self {
@@ -96,6 +240,19 @@ impl AutoTileRule {
 		}
 	}
 
+	/// Returns the rule for a tile without any neighbors
+	///
+	/// All cardinal directions are set to `false`, while the diagonals are ignored.
+	pub fn isolated() -> Self {
+		Self {
+			north: Some(false),
+			east: Some(false),
+			south: Some(false),
+			west: Some(false),
+			..Default::default()
+		}
+	}
+
 	/// Returns a default rule where all directions are set to `true`
 	pub fn default_true() -> Self {
 		Self {
@@ -121,9 +278,41 @@ impl AutoTileRule {
 	}
 }
 
+impl Index<Direction> for AutoTileRule {
+	type Output = Option<bool>;
+
+	fn index(&self, direction: Direction) -> &Self::Output {
+		match direction {
+			Direction::North => &self.north,
+			Direction::NorthEast => &self.north_east,
+			Direction::East => &self.east,
+			Direction::SouthEast => &self.south_east,
+			Direction::South => &self.south,
+			Direction::SouthWest => &self.south_west,
+			Direction::West => &self.west,
+			Direction::NorthWest => &self.north_west,
+		}
+	}
+}
+
+impl IndexMut<Direction> for AutoTileRule {
+	fn index_mut(&mut self, direction: Direction) -> &mut Self::Output {
+		match direction {
+			Direction::North => &mut self.north,
+			Direction::NorthEast => &mut self.north_east,
+			Direction::East => &mut self.east,
+			Direction::SouthEast => &mut self.south_east,
+			Direction::South => &mut self.south,
+			Direction::SouthWest => &mut self.south_west,
+			Direction::West => &mut self.west,
+			Direction::NorthWest => &mut self.north_west,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use crate::prelude::AutoTileRule;
+	use crate::prelude::{AutoTileRule, Direction};
 
 	#[test]
 	fn should_be_subset() {
@@ -141,4 +330,108 @@ mod tests {
 		assert!(a.is_subset_of(&b));
 		assert!(!b.is_subset_of(&a));
 	}
+
+	#[test]
+	fn should_count_specified() {
+		assert_eq!(0, AutoTileRule::default().specified_count());
+		assert_eq!(8, AutoTileRule::default_true().specified_count());
+		assert_eq!(8, AutoTileRule::default_false().specified_count());
+
+		let rule = AutoTileRule {
+			north: Some(true),
+			south_west: Some(false),
+			..Default::default()
+		};
+		assert_eq!(2, rule.specified_count());
+	}
+
+	#[test]
+	fn should_be_fully_specified() {
+		assert!(AutoTileRule::default_true().is_fully_specified());
+		assert!(AutoTileRule::default_false().is_fully_specified());
+		assert!(!AutoTileRule::default().is_fully_specified());
+
+		let rule = AutoTileRule {
+			north_west: None,
+			..AutoTileRule::default_true()
+		};
+		assert!(!rule.is_fully_specified());
+	}
+
+	#[test]
+	fn should_rotate_cw() {
+		let rule = AutoTileRule {
+			north: Some(true),
+			east: Some(false),
+			north_west: Some(true),
+			south_east: Some(false),
+			..Default::default()
+		};
+		let expected = AutoTileRule {
+			east: Some(true),
+			south: Some(false),
+			north_east: Some(true),
+			south_west: Some(false),
+			..Default::default()
+		};
+
+		assert_eq!(expected, rule.rotated_cw());
+		assert_eq!(
+			rule,
+			rule.rotated_cw().rotated_cw().rotated_cw().rotated_cw()
+		);
+	}
+
+	#[test]
+	fn should_index_by_direction() {
+		let mut rule = AutoTileRule::default();
+		rule[Direction::NorthEast] = Some(true);
+		rule[Direction::West] = Some(false);
+
+		assert_eq!(Some(true), rule.north_east);
+		assert_eq!(Some(false), rule[Direction::West]);
+		assert_eq!(2, rule.iter().filter(|(.., value)| value.is_some()).count());
+
+		// Every direction maps to a distinct field
+		let mut rule = AutoTileRule::default();
+		for direction in Direction::ALL {
+			rule[direction] = Some(true);
+		}
+		assert_eq!(AutoTileRule::default_true(), rule);
+	}
+
+	#[test]
+	fn should_convert_mask() {
+		let mask = [true, false, true, true, false, false, true, false];
+		let rule = AutoTileRule::from_mask(mask);
+
+		assert!(rule.is_fully_specified());
+		assert_eq!(Some(true), rule.east);
+		assert_eq!(Some(false), rule.south_west);
+		assert_eq!(mask.map(Some), rule.to_mask());
+	}
+
+	#[test]
+	fn should_conflict() {
+		let values = [None, Some(true), Some(false)];
+		for lhs in values {
+			for rhs in values {
+				let a = AutoTileRule {
+					east: lhs,
+					..Default::default()
+				};
+				let b = AutoTileRule {
+					east: rhs,
+					..Default::default()
+				};
+
+				let expected = matches!(
+					(lhs, rhs),
+					(Some(true), Some(false)) | (Some(false), Some(true))
+				);
+				assert_eq!(expected, a.conflicts_with(&b), "{:?} vs {:?}", lhs, rhs);
+				assert_eq!(expected, b.conflicts_with(&a), "{:?} vs {:?}", rhs, lhs);
+			}
+		}
+	}
 }
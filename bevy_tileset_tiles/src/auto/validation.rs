@@ -0,0 +1,256 @@
+use crate::prelude::AutoTileRule;
+
+/// An issue found when validating the rules of an auto tile
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RuleIssue {
+	/// The rule at `index` can never be selected
+	///
+	/// Since auto tiles are selected by finding the _first_ rule that matches, a rule is unreachable
+	/// if every neighbor pattern it matches is already matched by one of the earlier rules. These
+	/// earlier rules are listed in `shadowed_by`.
+	///
+	/// Any [isolated](AutoTileRule::isolated) rule is checked before all other rules, so it is
+	/// considered to come first regardless of where it was defined.
+	Shadowed {
+		index: usize,
+		shadowed_by: Vec<usize>,
+	},
+	/// No rule matches the given neighbor pattern
+	///
+	/// The pattern is fully specified, where `Some(true)` means a neighbor is present and `Some(false)`
+	/// means it is absent. When this pattern occurs, the fallback rule (or the last rule) is used instead.
+	Unmatched(AutoTileRule),
+}
+
+/// Validates a set of auto tile rules (in their defined order)
+///
+/// This checks for rules that can never be selected because they are shadowed by earlier rules,
+/// as well as neighbor patterns that no rule matches.
+///
+/// Rules are checked in the same order they are selected: any [isolated](AutoTileRule::isolated)
+/// rule first, followed by the remaining rules in their defined order.
+///
+/// # Arguments
+///
+/// * `rules`: The rules to validate
+///
+/// returns: Result<(), Vec<RuleIssue>>
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_tileset_tiles::prelude::*;
+///
+/// let rules = [
+/// 	AutoTileRule::default(),
+/// 	AutoTileRule { north: Some(true), ..Default::default() },
+/// ];
+///
+/// let issues = validate_rules(rules).unwrap_err();
+/// assert_eq!(vec![RuleIssue::Shadowed { index: 1, shadowed_by: vec![0] }], issues);
+/// ```
+pub fn validate_rules(rules: impl IntoIterator<Item = AutoTileRule>) -> Result<(), Vec<RuleIssue>> {
+	let rules = rules.into_iter().collect::<Vec<_>>();
+	let patterns = (0..=u8::MAX).map(pattern_from_bits).collect::<Vec<_>>();
+	let mut issues = Vec::new();
+
+	// Isolated rules are always checked first
+	let isolated = AutoTileRule::isolated();
+	let (mut order, rest): (Vec<usize>, Vec<usize>) =
+		(0..rules.len()).partition(|index| rules[*index] == isolated);
+	order.extend(rest);
+
+	for (position, &index) in order.iter().enumerate() {
+		let rule = &rules[index];
+		let mut earlier = order[..position].to_vec();
+		earlier.sort_unstable();
+
+		let matched = patterns
+			.iter()
+			.filter(|pattern| matches_pattern(rule, pattern))
+			.collect::<Vec<_>>();
+
+		let is_reachable = matched.iter().any(|pattern| {
+			!earlier
+				.iter()
+				.any(|earlier_index| matches_pattern(&rules[*earlier_index], pattern))
+		});
+
+		if !is_reachable {
+			let shadowed_by = earlier
+				.into_iter()
+				.filter(|earlier_index| {
+					matched
+						.iter()
+						.any(|pattern| matches_pattern(&rules[*earlier_index], pattern))
+				})
+				.collect();
+			issues.push(RuleIssue::Shadowed { index, shadowed_by });
+		}
+	}
+	issues.sort_by_key(|issue| match issue {
+		RuleIssue::Shadowed { index, .. } => *index,
+		RuleIssue::Unmatched(..) => usize::MAX,
+	});
+
+	for pattern in &patterns {
+		if !rules.iter().any(|rule| matches_pattern(rule, pattern)) {
+			issues.push(RuleIssue::Unmatched(AutoTileRule {
+				north: Some(pattern.north.is_some()),
+				east: Some(pattern.east.is_some()),
+				south: Some(pattern.south.is_some()),
+				west: Some(pattern.west.is_some()),
+				north_east: Some(pattern.north_east.is_some()),
+				north_west: Some(pattern.north_west.is_some()),
+				south_east: Some(pattern.south_east.is_some()),
+				south_west: Some(pattern.south_west.is_some()),
+			}));
+		}
+	}
+
+	if issues.is_empty() {
+		Ok(())
+	} else {
+		Err(issues)
+	}
+}
+
+/// Checks if the given rule would be selected for the given neighbor pattern
+fn matches_pattern(rule: &AutoTileRule, pattern: &AutoTileRule) -> bool {
+	rule.is_subset_of(pattern)
+}
+
+/// Creates a neighbor pattern from the given bits
+///
+/// This mirrors how rules are generated for placed tiles: present neighbors are marked `Some(true)`
+/// while absent neighbors are left as `None`. A pattern without any neighbors is therefore
+/// entirely `None`, which is what selects the [isolated](AutoTileRule::isolated) rule.
+fn pattern_from_bits(bits: u8) -> AutoTileRule {
+	let is_set = |bit: u8| (bits & (1 << bit) != 0).then_some(true);
+	AutoTileRule {
+		north: is_set(0),
+		east: is_set(1),
+		south: is_set(2),
+		west: is_set(3),
+		north_east: is_set(4),
+		north_west: is_set(5),
+		south_east: is_set(6),
+		south_west: is_set(7),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	#[test]
+	fn should_find_shadowed_rule() {
+		let rules = [
+			AutoTileRule {
+				north: Some(true),
+				..Default::default()
+			},
+			AutoTileRule {
+				north: Some(true),
+				south: Some(true),
+				..Default::default()
+			},
+			AutoTileRule::default(),
+		];
+
+		assert_eq!(
+			Err(vec![RuleIssue::Shadowed {
+				index: 1,
+				shadowed_by: vec![0]
+			}]),
+			validate_rules(rules)
+		);
+	}
+
+	#[test]
+	fn should_find_collectively_shadowed_rule() {
+		let rules = [
+			AutoTileRule {
+				north: Some(true),
+				..Default::default()
+			},
+			AutoTileRule {
+				north: Some(false),
+				..Default::default()
+			},
+			AutoTileRule {
+				east: Some(true),
+				..Default::default()
+			},
+		];
+
+		assert_eq!(
+			Err(vec![RuleIssue::Shadowed {
+				index: 2,
+				shadowed_by: vec![0, 1]
+			}]),
+			validate_rules(rules)
+		);
+	}
+
+	#[test]
+	fn should_find_unmatched_patterns() {
+		let rules = [AutoTileRule {
+			north: Some(true),
+			..Default::default()
+		}];
+
+		let issues = validate_rules(rules).unwrap_err();
+		// Every pattern without a northern neighbor is unmatched
+		assert_eq!(128, issues.len());
+		assert!(issues.iter().all(|issue| matches!(
+			issue,
+			RuleIssue::Unmatched(AutoTileRule {
+				north: Some(false),
+				..
+			})
+		)));
+	}
+
+	#[test]
+	fn should_check_isolated_rule_first() {
+		let rules = [AutoTileRule::default(), AutoTileRule::isolated()];
+		assert_eq!(Ok(()), validate_rules(rules));
+
+		// An isolated rule shadows rules for isolated tiles, even ones defined before it
+		let rules = [
+			AutoTileRule {
+				north: Some(true),
+				..Default::default()
+			},
+			AutoTileRule::default_false(),
+			AutoTileRule::isolated(),
+			AutoTileRule::default(),
+		];
+		assert_eq!(
+			Err(vec![RuleIssue::Shadowed {
+				index: 1,
+				shadowed_by: vec![2]
+			}]),
+			validate_rules(rules)
+		);
+	}
+
+	#[test]
+	fn should_be_valid() {
+		let rules = [
+			AutoTileRule {
+				north: Some(true),
+				south: Some(true),
+				..Default::default()
+			},
+			AutoTileRule {
+				north: Some(true),
+				..Default::default()
+			},
+			AutoTileRule::default(),
+		];
+
+		assert_eq!(Ok(()), validate_rules(rules));
+	}
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TileError {
+	#[error("could not read directory: {0:?}")]
+	IoError(std::io::Error),
+	#[error("unknown tile suffixes: {0:?}")]
+	UnknownSuffixes(Vec<String>),
+	#[error("variant weight must be positive and finite, found {0}")]
+	InvalidWeight(f32),
+	#[error("auto tile must contain at least one variant")]
+	NoVariants,
+}
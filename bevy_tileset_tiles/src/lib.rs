@@ -1,9 +1,18 @@
 //! Tile data, including tile definitions (for config files) and auto tiling
 pub mod prelude {
-	pub use super::animated::{AnimatedTileData, AnimatedTileDef, AnimatedTileHandle};
+	pub use super::animated::{
+		AnimatedSheetDef, AnimatedSheetHandle, AnimatedTileData, AnimatedTileDef,
+		AnimatedTileHandle, FrameRange,
+	};
 	#[cfg(feature = "auto-tile")]
-	pub use super::auto::{AutoTileData, AutoTileDef, AutoTileHandle, AutoTileRule};
-	pub use super::tile::{TileData, TileDef, TileDefType, TileHandle, TileHandleType, TileType};
+	pub use super::auto::{
+		validate_rules, AutoTileData, AutoTileDef, AutoTileHandle, AutoTileRule, Direction,
+		RuleIssue, SuffixScheme,
+	};
+	pub use super::tile::{
+		PartialTileDef, StandardTileDef, TileData, TileDef, TileDefType, TileFlip, TileHandle,
+		TileHandleType, TileType, TileTypeKind,
+	};
 	#[cfg(feature = "variants")]
 	pub use super::variants::{
 		SimpleTileDefType, SimpleTileHandle, SimpleTileType, VariantTileData, VariantTileDef,
@@ -14,6 +23,7 @@ pub mod prelude {
 pub mod animated;
 #[cfg(feature = "auto-tile")]
 pub mod auto;
+pub mod error;
 pub mod tile;
 #[cfg(feature = "variants")]
 pub mod variants;
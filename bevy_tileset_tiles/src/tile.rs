@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use bevy_asset::{AssetServer, Handle, LoadState};
 use bevy_render::texture::Image;
 use serde::{Deserialize, Serialize};
@@ -9,16 +11,33 @@ use crate::prelude::{AnimatedTileData, AnimatedTileDef, AnimatedTileHandle};
 use crate::variants::*;
 
 /// Top-level structure defining a tile
+///
+/// Tile data can always be serialized, but deserializing it requires the `serde` feature.
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct TileData {
 	/// The name of this tile
 	name: String,
 	/// The actual tile data
 	tile: TileType,
+	/// The default orientation of this tile
+	flip: TileFlip,
+	/// The key of the sound effect associated with this tile (if any)
+	///
+	/// This is metadata only and is never played by this crate.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	sfx: Option<String>,
+	/// The set of all indices used by this tile
+	///
+	/// This is used to speed up [`TileData::contains_index`] and is not available on
+	/// deserialized tiles.
+	#[serde(skip)]
+	indices: Option<HashSet<usize>>,
 }
 
 /// An enum defining the tile's type
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub enum TileType {
 	/// A standard tile
 	Standard(usize),
@@ -32,11 +51,28 @@ pub enum TileType {
 	Auto(Vec<AutoTileData>),
 }
 
+/// The kind of a [`TileType`], without any of its data
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum TileTypeKind {
+	/// A standard tile
+	Standard,
+	/// A frame-based animated tile
+	Animated,
+	/// A collection of tiles to randomly sample
+	#[cfg(feature = "variants")]
+	Variant,
+	/// A collection of auto tiles
+	#[cfg(feature = "auto-tile")]
+	Auto,
+}
+
 /// Top-level structure defining a tile
 #[derive(Debug, Clone)]
 pub struct TileHandle {
 	pub name: String,
 	pub tile: TileHandleType,
+	pub flip: TileFlip,
+	pub sfx: Option<String>,
 }
 
 /// An enum defining the tile's type
@@ -57,13 +93,123 @@ pub struct TileDef {
 	pub name: String,
 	/// The actual tile data
 	pub tile: TileDefType,
+	/// The default orientation of this tile
+	///
+	/// Default: No flipping
+	#[serde(default)]
+	pub flip: TileFlip,
+	/// The key of a sound effect associated with this tile (i.e. played when placed or stepped on)
+	///
+	/// This is metadata only: it is carried through to the [`TileData`] and is never played by
+	/// this crate.
+	///
+	/// Default: None
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub sfx: Option<String>,
+}
+
+/// A [`TileDef`] that may inherit its fields from another definition file
+///
+/// Inheritance is shallow: any field given here replaces the base's field entirely.
+///
+/// # Examples
+///
+/// ```ron
+/// // tiles/lava-bright.ron
+/// (
+/// 	base: "lava.ron",
+/// 	name: "Bright Lava",
+/// )
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct PartialTileDef {
+	/// The path to the definition file to inherit from (relative to this file)
+	///
+	/// Default: None
+	#[serde(default)]
+	pub base: Option<String>,
+	/// The name of this tile
+	#[serde(default)]
+	pub name: Option<String>,
+	/// The actual tile data
+	#[serde(default)]
+	pub tile: Option<TileDefType>,
+	/// The default orientation of this tile
+	#[serde(default)]
+	pub flip: Option<TileFlip>,
+	/// The key of a sound effect associated with this tile (metadata only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub sfx: Option<String>,
+}
+
+impl PartialTileDef {
+	/// Fills in any missing fields using the given base definition
+	///
+	/// The base of the returned definition is taken from the given base (i.e. the next
+	/// definition up the chain).
+	///
+	/// # Arguments
+	///
+	/// * `base`: The definition to inherit from
+	///
+	/// returns: PartialTileDef
+	///
+	pub fn inherit(self, base: PartialTileDef) -> Self {
+		Self {
+			base: base.base,
+			name: self.name.or(base.name),
+			tile: self.tile.or(base.tile),
+			flip: self.flip.or(base.flip),
+			sfx: self.sfx.or(base.sfx),
+		}
+	}
+
+	/// Converts this into a [`TileDef`], returning `None` if either the name or tile is missing
+	pub fn into_def(self) -> Option<TileDef> {
+		Some(TileDef {
+			name: self.name?,
+			tile: self.tile?,
+			flip: self.flip.unwrap_or_default(),
+			sfx: self.sfx,
+		})
+	}
+}
+
+/// A structure defining how a tile should be flipped when placed
+///
+/// This allows a single texture to be reused for tiles that only differ in their orientation.
+/// A rotation can be achieved by combining these flags (e.g. `x` and `d` for a 90° rotation).
+///
+/// # Examples
+///
+/// ```ron
+/// (
+/// 	name: "My Flipped Tile",
+/// 	tile: Standard("textures/my_tile.png"),
+/// 	flip: (x: true),
+/// )
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TileFlip {
+	/// Flip along the x-axis
+	#[serde(default)]
+	pub x: bool,
+	/// Flip along the y-axis
+	#[serde(default)]
+	pub y: bool,
+	/// Flip along the anti-diagonal (swapping the x and y axes)
+	#[serde(default)]
+	pub d: bool,
 }
 
 /// An enum defining the tile's type
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub enum TileDefType {
 	/// Defines a plain old tile
-	Standard(String),
+	///
+	/// If given a list of paths, each path is treated as an equally weighted variant
+	/// (see [`StandardTileDef`])
+	Standard(StandardTileDef),
 	/// Defines a tile with a frame-based animation
 	Animated(AnimatedTileDef),
 	/// Defines a set of tiles to randomly sample
@@ -77,6 +223,48 @@ pub enum TileDefType {
 	Auto(Vec<AutoTileDef>),
 }
 
+/// The texture(s) of a standard tile definition
+///
+/// # Examples
+///
+/// ```ron
+/// (
+/// 	name: "My Tile",
+/// 	tile: Standard("textures/my_tile.png"),
+/// )
+/// ```
+///
+/// Or, with the `variants` feature, a list of paths can be given to randomly choose between them
+/// (this is the same as a [`TileDefType::Variant`] where every variant has a weight of `1.0`):
+///
+/// ```ron
+/// (
+/// 	name: "My Random Tile",
+/// 	tile: Standard(["textures/tile_a.png", "textures/tile_b.png"]),
+/// )
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum StandardTileDef {
+	/// The path to a single texture
+	Single(String),
+	/// The paths to multiple, equally weighted, textures
+	#[cfg(feature = "variants")]
+	Multiple(Vec<String>),
+}
+
+impl From<String> for StandardTileDef {
+	fn from(path: String) -> Self {
+		Self::Single(path)
+	}
+}
+
+impl From<&str> for StandardTileDef {
+	fn from(path: &str) -> Self {
+		Self::Single(path.to_string())
+	}
+}
+
 impl TileData {
 	/// Create a new [`TileData`] instance
 	///
@@ -98,7 +286,26 @@ impl TileData {
 	/// );
 	/// ```
 	pub fn new(name: String, tile: TileType) -> Self {
-		Self { name, tile }
+		let indices = Some(tile.iter_indices().collect());
+		Self {
+			name,
+			tile,
+			flip: TileFlip::default(),
+			sfx: None,
+			indices,
+		}
+	}
+
+	/// Sets the default orientation of this tile
+	pub fn with_flip(mut self, flip: TileFlip) -> Self {
+		self.flip = flip;
+		self
+	}
+
+	/// Sets the key of the sound effect associated with this tile
+	pub fn with_sfx(mut self, sfx: Option<String>) -> Self {
+		self.sfx = sfx;
+		self
 	}
 
 	/// Gets the name of this tile
@@ -111,6 +318,51 @@ impl TileData {
 		&self.tile
 	}
 
+	/// Gets the kind of this tile
+	pub fn kind(&self) -> TileTypeKind {
+		self.tile.kind()
+	}
+
+	/// Gets the default orientation of this tile
+	pub fn flip(&self) -> TileFlip {
+		self.flip
+	}
+
+	/// Gets the key of the sound effect associated with this tile (if any)
+	pub fn sfx(&self) -> Option<&str> {
+		self.sfx.as_deref()
+	}
+
+	/// Gets all texture indices used by this tile
+	///
+	/// This includes every animation frame as well as the indices of all variants.
+	/// Indices are returned in the order they are defined and may contain duplicates
+	/// if multiple variants share a texture.
+	///
+	/// returns: Vec<usize>
+	///
+	pub fn indices(&self) -> Vec<usize> {
+		self.tile.iter_indices().collect()
+	}
+
+	/// Checks if the given index exists within this tile
+	///
+	/// Unlike [`TileType::contains_index`], this is a constant-time lookup (unless this
+	/// tile was deserialized).
+	///
+	/// # Arguments
+	///
+	/// * `index`: The index to check
+	///
+	/// returns: bool
+	///
+	pub fn contains_index(&self, index: &usize) -> bool {
+		match &self.indices {
+			Some(indices) => indices.contains(index),
+			None => self.tile.contains_index(index),
+		}
+	}
+
 	/// Checks if the underlying tile is a [`TileType::Standard`] tile
 	pub fn is_standard(&self) -> bool {
 		matches!(self.tile, TileType::Standard(..))
@@ -132,9 +384,49 @@ impl TileData {
 	pub fn is_auto(&self) -> bool {
 		matches!(self.tile, TileType::Auto(..))
 	}
+
+	/// Validates the rules of the underlying [`TileType::Auto`] tile
+	///
+	/// Non-auto tiles are always considered valid.
+	///
+	/// See [`validate_rules`] for details.
+	#[cfg(feature = "auto-tile")]
+	pub fn validate_rules(&self) -> Result<(), Vec<RuleIssue>> {
+		match &self.tile {
+			TileType::Auto(autos) => validate_rules(autos.iter().map(|auto| auto.rule())),
+			_ => Ok(()),
+		}
+	}
+}
+
+impl TileDef {
+	/// Validates the rules of the underlying [`TileDefType::Auto`] tile
+	///
+	/// Non-auto tiles are always considered valid.
+	///
+	/// See [`validate_rules`] for details.
+	#[cfg(feature = "auto-tile")]
+	pub fn validate_rules(&self) -> Result<(), Vec<RuleIssue>> {
+		match &self.tile {
+			TileDefType::Auto(autos) => validate_rules(autos.iter().map(|auto| auto.rule)),
+			_ => Ok(()),
+		}
+	}
 }
 
 impl TileType {
+	/// Gets the kind of this tile type
+	pub fn kind(&self) -> TileTypeKind {
+		match self {
+			Self::Standard(..) => TileTypeKind::Standard,
+			Self::Animated(..) => TileTypeKind::Animated,
+			#[cfg(feature = "variants")]
+			Self::Variant(..) => TileTypeKind::Variant,
+			#[cfg(feature = "auto-tile")]
+			Self::Auto(..) => TileTypeKind::Auto,
+		}
+	}
+
 	/// Checks if the given index exists within this tile
 	///
 	/// # Arguments
@@ -156,6 +448,23 @@ impl TileType {
 				.any(|v| v.tile().contains_index(index)),
 		}
 	}
+
+	/// Iterate over all indices used by this tile
+	pub fn iter_indices(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+		match self {
+			Self::Standard(idx) => Box::new(std::iter::once(*idx)),
+			Self::Animated(anim) => Box::new(anim.start()..=anim.end()),
+			#[cfg(feature = "variants")]
+			Self::Variant(variants) => Box::new(variants.iter().flat_map(|v| v.tile().iter_indices())),
+			#[cfg(feature = "auto-tile")]
+			Self::Auto(autos) => Box::new(
+				autos
+					.iter()
+					.flat_map(|a| a.variants())
+					.flat_map(|v| v.tile().iter_indices()),
+			),
+		}
+	}
 }
 
 impl TileHandle {
@@ -163,6 +472,8 @@ impl TileHandle {
 		Self {
 			name: name.into(),
 			tile: TileHandleType::Standard(handle),
+			flip: TileFlip::default(),
+			sfx: None,
 		}
 	}
 
@@ -170,6 +481,8 @@ impl TileHandle {
 		Self {
 			name: name.into(),
 			tile: TileHandleType::Animated(handle),
+			flip: TileFlip::default(),
+			sfx: None,
 		}
 	}
 
@@ -178,6 +491,8 @@ impl TileHandle {
 		Self {
 			name: name.into(),
 			tile: TileHandleType::Variant(handles.clone()),
+			flip: TileFlip::default(),
+			sfx: None,
 		}
 	}
 
@@ -186,9 +501,23 @@ impl TileHandle {
 		Self {
 			name: name.into(),
 			tile: TileHandleType::Auto(handles.clone()),
+			flip: TileFlip::default(),
+			sfx: None,
 		}
 	}
 
+	/// Sets the default orientation of this tile
+	pub fn with_flip(mut self, flip: TileFlip) -> Self {
+		self.flip = flip;
+		self
+	}
+
+	/// Sets the key of the sound effect associated with this tile
+	pub fn with_sfx(mut self, sfx: Option<String>) -> Self {
+		self.sfx = sfx;
+		self
+	}
+
 	pub fn is_loaded(&self, asset_server: &AssetServer) -> bool {
 		self.get_load_state(asset_server) == LoadState::Loaded
 	}
@@ -200,7 +529,7 @@ impl TileHandle {
 	pub fn iter_handles(&self) -> Box<dyn Iterator<Item = &Handle<Image>> + '_> {
 		match &self.tile {
 			TileHandleType::Standard(handle) => Box::new(std::iter::once(handle)),
-			TileHandleType::Animated(anim) => Box::new(anim.frames.iter()),
+			TileHandleType::Animated(anim) => Box::new(anim.iter_handles()),
 			#[cfg(feature = "variants")]
 			TileHandleType::Variant(variants) => Box::new(iter_variant_handles(variants.iter())),
 			#[cfg(feature = "auto-tile")]
@@ -219,7 +548,7 @@ fn iter_variant_handles<'a>(
 		.map(|variant| {
 			let iter: Box<dyn Iterator<Item = &Handle<Image>>> = match &variant.tile {
 				SimpleTileHandle::Standard(handle) => Box::new(std::iter::once(handle)),
-				SimpleTileHandle::Animated(anim) => Box::new(anim.frames.iter()),
+				SimpleTileHandle::Animated(anim) => Box::new(anim.iter_handles()),
 			};
 			iter
 		})
@@ -229,6 +558,7 @@ fn iter_variant_handles<'a>(
 #[cfg(test)]
 mod tests {
 	use bevy_asset::Handle;
+	use bevy_math::UVec2;
 
 	use crate::prelude::*;
 
@@ -249,6 +579,8 @@ mod tests {
 			AnimatedTileHandle {
 				speed: 1.0,
 				frames: vec![Handle::default(); 3],
+				sheet: None,
+				indices: None,
 			},
 		);
 		let mut anim_iter = anim.iter_handles();
@@ -260,6 +592,123 @@ mod tests {
 		assert!(anim_iter.next().is_none());
 	}
 
+	#[test]
+	fn should_count_frames() {
+		assert_eq!(3, AnimatedTileData::new(1.0, 3, 5).frame_count());
+		assert_eq!(1, AnimatedTileData::new(1.0, 4, 4).frame_count());
+	}
+
+	#[cfg(feature = "auto-tile")]
+	#[test]
+	fn should_validate_constructors() {
+		use crate::error::TileError;
+
+		let tile = SimpleTileType::Standard(0);
+		assert!(VariantTileData::try_new(0.5, tile).is_ok());
+		for weight in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+			assert!(matches!(
+				VariantTileData::try_new(weight, tile),
+				Err(TileError::InvalidWeight(..))
+			));
+		}
+
+		assert!(matches!(
+			AutoTileData::try_new(AutoTileRule::default(), Vec::new()),
+			Err(TileError::NoVariants)
+		));
+		assert!(AutoTileData::try_new(
+			AutoTileRule::default(),
+			vec![VariantTileData::new(1.0, tile)]
+		)
+		.is_ok());
+	}
+
+	#[test]
+	fn should_inherit_from_base() {
+		let base = PartialTileDef {
+			base: None,
+			name: Some(String::from("Lava")),
+			tile: Some(TileDefType::Standard("lava.png".into())),
+			flip: Some(TileFlip {
+				x: true,
+				..Default::default()
+			}),
+			sfx: Some(String::from("sizzle")),
+		};
+		let child = PartialTileDef {
+			base: Some(String::from("lava.ron")),
+			name: Some(String::from("Bright Lava")),
+			..Default::default()
+		};
+
+		let def = child.inherit(base).into_def().unwrap();
+		assert_eq!("Bright Lava", def.name);
+		assert!(def.flip.x);
+		assert_eq!(Some("sizzle"), def.sfx.as_deref());
+
+		assert!(PartialTileDef::default().into_def().is_none());
+	}
+
+	#[test]
+	fn should_contain_index() {
+		let tile = TileData::new(
+			String::from("Animated"),
+			TileType::Animated(AnimatedTileData::new(1.0, 3, 5)),
+		);
+		let deserialized = TileData {
+			indices: None,
+			..tile.clone()
+		};
+
+		for index in 0..8 {
+			let expected = (3..=5).contains(&index);
+			assert_eq!(expected, tile.contains_index(&index));
+			assert_eq!(expected, deserialized.contains_index(&index));
+		}
+	}
+
+	#[cfg(feature = "variants")]
+	#[test]
+	fn should_get_indices() {
+		let tile = TileData::new(
+			String::from("Variant"),
+			TileType::Variant(vec![
+				VariantTileData::new(1.0, SimpleTileType::Standard(0)),
+				VariantTileData::new(
+					1.0,
+					SimpleTileType::Animated(AnimatedTileData::new(1.0, 4, 6)),
+				),
+			]),
+		);
+
+		assert_eq!(vec![0, 4, 5, 6], tile.indices());
+	}
+
+	#[test]
+	fn should_iter_animated_sheet() {
+		let anim = TileHandle::new_animated(
+			"Animated",
+			AnimatedTileHandle {
+				speed: 1.0,
+				frames: vec![Handle::default(); 2],
+				sheet: Some(AnimatedSheetHandle {
+					handle: Handle::default(),
+					frame_size: UVec2::splat(16),
+					frames: 4,
+				}),
+				indices: None,
+			},
+		);
+		let mut anim_iter = anim.iter_handles();
+		// Animated (2)
+		assert!(anim_iter.next().is_some());
+		assert!(anim_iter.next().is_some());
+		// Sheet (1)
+		assert!(anim_iter.next().is_some());
+		// End
+		assert!(anim_iter.next().is_none());
+	}
+
 	#[cfg(feature = "variants")]
 	#[test]
 	fn should_iter_variant() {
@@ -275,6 +724,8 @@ mod tests {
 					tile: SimpleTileHandle::Animated(AnimatedTileHandle {
 						speed: 1.0,
 						frames: vec![Handle::default(); 3],
+						sheet: None,
+						indices: None,
 					}),
 				},
 			],
@@ -298,6 +749,8 @@ mod tests {
 			vec![
 				AutoTileHandle {
 					rule: AutoTileRule::default(),
+					fallback: false,
+					fallback_tile: None,
 					variants: vec![
 						VariantTileHandle {
 							weight: 1.0,
@@ -308,12 +761,16 @@ mod tests {
 							tile: SimpleTileHandle::Animated(AnimatedTileHandle {
 								speed: 1.0,
 								frames: vec![Handle::default(); 3],
+								sheet: None,
+								indices: None,
 							}),
 						},
 					],
 				},
 				AutoTileHandle {
 					rule: AutoTileRule::default(),
+					fallback: false,
+					fallback_tile: None,
 					variants: vec![
 						VariantTileHandle {
 							weight: 1.0,
@@ -324,6 +781,8 @@ mod tests {
 							tile: SimpleTileHandle::Animated(AnimatedTileHandle {
 								speed: 1.0,
 								frames: vec![Handle::default(); 3],
+								sheet: None,
+								indices: None,
 							}),
 						},
 					],
@@ -1,3 +1,4 @@
+use crate::error::TileError;
 use crate::prelude::{AnimatedTileData, AnimatedTileDef, AnimatedTileHandle};
 use bevy_asset::Handle;
 use bevy_render::texture::Image;
@@ -8,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// A _variant_ essentially wraps a [simple](SimpleTileType) tile and gives it
 /// a weight. This weight is used to define how likely it should be picked at random
 #[derive(Debug, Copy, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct VariantTileData {
 	/// The weight of this variant (used for random sampling)
 	weight: f32,
@@ -20,6 +22,7 @@ pub struct VariantTileData {
 /// These are "simple" types in that their inner types are not _too_ complex
 /// or heavily nested
 #[derive(Debug, Copy, Clone, Serialize)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub enum SimpleTileType {
 	Standard(usize),
 	Animated(AnimatedTileData),
@@ -45,6 +48,30 @@ pub enum SimpleTileHandle {
 ///
 /// A _variant_ essentially wraps a [simple](SimpleTileDefType) tile and gives it
 /// a weight. This weight is used to define how likely it should be picked at random
+///
+/// Standard and animated variants can be freely mixed, and each animated variant keeps its own
+/// frames and speed. This allows one of several animations to be picked at random.
+///
+/// # Examples
+///
+/// ```ron
+/// (
+/// 	name: "Water",
+/// 	tile: Variant([
+/// 		(
+/// 			weight: 4.0,
+/// 			tile: Animated((speed: 2.0, frames: ["water/calm-1.png", "water/calm-2.png"])),
+/// 		),
+/// 		(
+/// 			tile: Animated((speed: 8.0, frames: ["water/rough-1.png", "water/rough-2.png"])),
+/// 		),
+/// 		(
+/// 			weight: 0.5,
+/// 			tile: Standard("water/still.png"),
+/// 		),
+/// 	]),
+/// )
+/// ```
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct VariantTileDef {
 	/// The weight of this variant (used for random sampling)
@@ -71,6 +98,23 @@ impl VariantTileData {
 		Self { weight, tile }
 	}
 
+	/// Create a new [`VariantTileData`], ensuring its weight can be used for random selection
+	///
+	/// # Arguments
+	///
+	/// * `weight`: The weight of this variant (must be positive and finite)
+	/// * `tile`: The underlying tile data
+	///
+	/// returns: Result<VariantTileData, TileError>
+	///
+	pub fn try_new(weight: f32, tile: SimpleTileType) -> Result<Self, TileError> {
+		if weight.is_finite() && weight > 0.0 {
+			Ok(Self::new(weight, tile))
+		} else {
+			Err(TileError::InvalidWeight(weight))
+		}
+	}
+
 	/// Gets the weight of this variant
 	pub fn weight(&self) -> f32 {
 		self.weight
@@ -97,6 +141,14 @@ impl SimpleTileType {
 			Self::Animated(anim) => anim.start() <= *index && *index <= anim.end(),
 		}
 	}
+
+	/// Iterate over all indices used by this tile
+	pub fn iter_indices(&self) -> impl Iterator<Item = usize> {
+		match self {
+			Self::Standard(idx) => *idx..=*idx,
+			Self::Animated(anim) => anim.start()..=anim.end(),
+		}
+	}
 }
 
 /// Gets the default variant weight
@@ -46,7 +46,11 @@ impl Default for MyTileset {
 }
 
 /// Starts the tileset loading process
-fn load_tileset(mut my_tileset: ResMut<MyTileset>, asset_server: Res<AssetServer>) {
+fn load_tileset(
+	mut my_tileset: ResMut<MyTileset>,
+	asset_server: Res<AssetServer>,
+	settings: Res<TilesetLoadSettings>,
+) {
 	// You can dynamically load the TileDef config files
 	let asset_path = FileAssetIo::get_base_path().join("assets");
 	let dirt_path = asset_path.join("tiles/dirt.ron");
@@ -55,11 +59,12 @@ fn load_tileset(mut my_tileset: ResMut<MyTileset>, asset_server: Res<AssetServer
 	let dirt_bytes = std::fs::read(dirt_path).unwrap();
 	let glass_bytes = std::fs::read(glass_path).unwrap();
 
-	let dirt_tile = ron::de::from_bytes::<TileDef>(&dirt_bytes).unwrap();
-	let glass_tile = ron::de::from_bytes::<TileDef>(&glass_bytes).unwrap();
+	// Parsing through the load settings upgrades old files just like the asset loader
+	let dirt_tile = settings.parse_tile_def(&dirt_bytes).unwrap();
+	let glass_tile = settings.parse_tile_def(&glass_bytes).unwrap();
 
-	// Automatically generate the TileHandle collection
-	let mut handles = load_tile_handles(vec![dirt_tile, glass_tile], &asset_server);
+	// Automatically generate the TileHandle collection (expanding any path variables)
+	let mut handles = settings.load_tile_handles(vec![dirt_tile, glass_tile], &asset_server);
 
 	// You can also manually construct the TileHandle yourself
 	let grass_handle: Handle<Image> = asset_server.load("tiles/grass.png");
@@ -91,18 +96,20 @@ fn check_loaded(
 	}
 
 	// Build the tileset
-	let mut builder = TilesetBuilder::default();
-	// We use a reference here because we still need to keep these strong handles loaded
-	// (the RawTileset will only store weak handles)
+	// By default, the RawTileset only stores weak handles to the tile images. Since we want to display
+	// one of those images later, we tell the builder to keep strong handles instead.
 	let tiles = my_tileset.tiles.as_ref().unwrap();
-	for (group_id, tile) in tiles.iter().enumerate() {
-		builder
-			.add_tile(tile.clone(), group_id as TileGroupId, &textures)
-			.unwrap();
-	}
-
-	let raw_tileset = builder
-		.build("My Dynamic Tileset", 123, &mut textures)
+	let raw_tileset = TilesetBuilder::default()
+		.with_strong_handles(true)
+		.build_from(
+			tiles
+				.iter()
+				.enumerate()
+				.map(|(group_id, tile)| (group_id as TileGroupId, tile.clone())),
+			"My Dynamic Tileset",
+			123,
+			&mut textures,
+		)
 		.unwrap();
 
 	// We could also choose to add it to the `Assets<Tileset>` resource so we could use `Tilesets`, but we'll
@@ -118,12 +125,7 @@ fn check_loaded(
 }
 
 /// Shows the tileset
-fn show_tileset(
-	mut commands: Commands,
-	my_tileset: Res<MyTileset>,
-	mut has_ran: Local<bool>,
-	mut textures: ResMut<Assets<Image>>,
-) {
+fn show_tileset(mut commands: Commands, my_tileset: Res<MyTileset>, mut has_ran: Local<bool>) {
 	if my_tileset.raw_tileset.is_none() || *has_ran {
 		return;
 	}
@@ -146,9 +148,8 @@ fn show_tileset(
 			TileIndex::Standard(index) => {
 				// Do something standard
 				if let Some(handle) = raw_tileset.get_tile_handle(index) {
-					let mut texture = handle.clone();
-					// Handles in the tileset are weak by default so we'll need to make it strong again so the image doesn't unload
-					texture.make_strong(&mut textures);
+					// This handle is strong since we built the tileset with `with_strong_handles(true)`
+					let texture = handle.clone();
 					commands.spawn(SpriteBundle {
 						texture,
 						transform: Transform::from_xyz(0.0, 48.0, 0.0),
@@ -156,7 +157,7 @@ fn show_tileset(
 					});
 				}
 			},
-			TileIndex::Animated(start, end, speed) => {
+			TileIndex::Animated { start, end, speed } => {
 				// Do something  ✨ animated ✨
 			},
 		}
@@ -1,7 +1,7 @@
 //! This example demonstrates the most basic setup for loading a tileset
 //!
-//! Essentially, all we need to do is load the config file via the `AssetServer` and keep
-//! a handle to the asset. That's it! From there, you can use the `Tilesets` system parameter
+//! Essentially, all we need to do is load the config file via the `TilesetHandles` resource, which
+//! keeps a handle to the asset for us. That's it! From there, you can use the `Tilesets` system parameter
 //! (or simply `Res<Assets<Tileset>>` if you prefer) to access the stored tile and texture atlas
 //! data.
 
@@ -14,21 +14,18 @@ fn main() {
 		// === Required === //
 		.add_plugins((DefaultPlugins, TilesetPlugin::default()))
 		// /== Required === //
-		.init_resource::<MyTileset>()
 		.add_systems(Startup, load_tileset)
 		.add_systems(Update, show_tileset)
 		.run();
 }
 
-#[derive(Resource, Default)]
-struct MyTileset {
-	/// This stores the handle to our tileset so it doesn't get unloaded
-	handle: Option<Handle<Tileset>>,
-}
+const TILESET_PATH: &str = "tilesets/my_tileset.ron";
 
 /// Starts the tileset loading process
-fn load_tileset(mut my_tileset: ResMut<MyTileset>, asset_server: Res<AssetServer>) {
-	my_tileset.handle = Some(asset_server.load("tilesets/my_tileset.ron"));
+///
+/// The `TilesetHandles` resource stores the handle to our tileset so it doesn't get unloaded
+fn load_tileset(mut handles: ResMut<TilesetHandles>, asset_server: Res<AssetServer>) {
+	handles.get_or_load(TILESET_PATH, &asset_server);
 }
 
 /// Shows the tileset
@@ -38,16 +35,18 @@ fn load_tileset(mut my_tileset: ResMut<MyTileset>, asset_server: Res<AssetServer
 fn show_tileset(
 	tilesets: Tilesets,
 	mut commands: Commands,
-	my_tileset: Res<MyTileset>,
+	handles: Res<TilesetHandles>,
 	mut has_ran: Local<bool>,
 ) {
-	if my_tileset.handle.is_none() || *has_ran || !tilesets.contains_name("My Awesome Tileset") {
+	let Some(handle) = handles.get(TILESET_PATH) else {
+		return;
+	};
+	if *has_ran || !tilesets.contains_name("My Awesome Tileset") {
 		return;
 	}
 
-	let handle = my_tileset.handle.as_ref().unwrap();
 	if let Some(_) = tilesets.get(handle) {
-		println!("Got tileset by handle! ({:?})", my_tileset.handle);
+		println!("Got tileset by handle! ({:?})", handle);
 	}
 	if let Some(tileset) = tilesets.get_by_id(&0) {
 		println!("Got tileset by ID! ({})", tileset.id());
@@ -81,7 +80,7 @@ fn show_tileset(
 						..Default::default()
 					});
 				},
-				TileIndex::Animated(start, end, speed) => {
+				TileIndex::Animated { start, end, speed } => {
 					// Do something  ✨ animated ✨
 				},
 			}
@@ -52,7 +52,7 @@
 //!
 //!   match tile_index {
 //!     TileIndex::Standard(texture_index) => { /* Do something */ },
-//!     TileIndex::Animated(start, end, speed) => { /* Do something */ },
+//!     TileIndex::Animated { start, end, speed } => { /* Do something */ },
 //!   }
 //! }
 //! ```